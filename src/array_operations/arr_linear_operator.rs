@@ -0,0 +1,52 @@
+use crate::{check_operand_length, LinearOperator, MatrixError};
+use num::Zero;
+use std::ops::{Add, Mul};
+
+impl<T, const N: usize> LinearOperator<T> for [[T; N]; N]
+where
+	T: Add<T, Output = T> + Mul<T, Output = T> + Zero + Copy,
+{
+	fn try_apply(&self, x: &[T]) -> Result<Vec<T>, MatrixError> {
+		check_operand_length((N, N), N, x)?;
+		Ok(self
+			.iter()
+			.map(|row| {
+				row.iter()
+					.zip(x.iter())
+					.fold(T::zero(), |sum, (a, x)| sum + *a * *x)
+			})
+			.collect())
+	}
+	fn try_apply_transpose(&self, x: &[T]) -> Result<Vec<T>, MatrixError> {
+		check_operand_length((N, N), N, x)?;
+		Ok((0..N)
+			.map(|column| {
+				self.iter()
+					.zip(x.iter())
+					.fold(T::zero(), |sum, (row, x)| sum + row[column] * *x)
+			})
+			.collect())
+	}
+}
+
+#[cfg(test)]
+mod test_arr_linear_operator {
+	use crate::transformations::{rotation_z, translation_3d};
+	use crate::{LinearOperator, Matrix3D, MatrixOperations, Point3D};
+
+	#[test]
+	fn test_arr_apply() {
+		let transform: Matrix3D =
+			translation_3d(1.0, 2.0, 3.0).matrix_mult_matrix(&rotation_z(0.3));
+		let point: Point3D = [1.0, -2.0, 0.5, 1.0];
+
+		assert_eq!(
+			transform.matrix_mult_point(&point).to_vec(),
+			transform.apply(&point)
+		);
+		assert_eq!(
+			transform.transpose().matrix_mult_point(&point).to_vec(),
+			transform.apply_transpose(&point)
+		);
+	}
+}
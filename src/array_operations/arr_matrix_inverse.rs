@@ -0,0 +1,44 @@
+use crate::{gauss_jordan_inverse, MatrixError, MatrixInverse};
+use num::Float;
+
+impl<T: Float, const N: usize> MatrixInverse for [[T; N]; N] {
+	type Output = T;
+	type MatrixOutput = [[T; N]; N];
+
+	fn try_matrix_inverse(&self, tolerance: T) -> Result<[[T; N]; N], MatrixError> {
+		if N == 0 {
+			return Err(MatrixError::EmptyOperand);
+		}
+		let identity = std::array::from_fn(|row| {
+			std::array::from_fn(|column| if row == column { T::one() } else { T::zero() })
+		});
+		gauss_jordan_inverse(*self, identity, N, tolerance)
+	}
+}
+
+#[cfg(test)]
+mod test_arr_matrix_inverse {
+	use crate::transformations::{rotation_axis_angle, scale_2d, translation_3d};
+	use crate::{Matrix2D, Matrix3D, MatrixError, MatrixInverse, MatrixOperations, I4};
+
+	#[test]
+	fn test_arr_matrix_inverse() {
+		let transform: Matrix3D = translation_3d(1.0, 2.0, 3.0)
+			.matrix_mult_matrix(&rotation_axis_angle(&[1.0, 1.0, 0.0], 0.5).unwrap());
+		let product = transform.matrix_mult_matrix(&transform.matrix_inverse(1e-12));
+		for row in 0..4 {
+			for column in 0..4 {
+				assert!((product[row][column] - I4[row][column]).abs() < 1e-9);
+			}
+		}
+		assert_eq!(
+			[[0.5, 0.0, 0.0], [0.0, 0.25, 0.0], [0.0, 0.0, 1.0]],
+			scale_2d(2.0, 4.0).matrix_inverse(1e-12)
+		);
+		let singular: Matrix2D = scale_2d(0.0, 1.0);
+		assert_eq!(
+			Err(MatrixError::Singular),
+			singular.try_matrix_inverse(1e-12)
+		);
+	}
+}
@@ -0,0 +1,175 @@
+use crate::decompositions::solve_system;
+use crate::{square_determinant, MatrixError, MatrixOperations};
+use num::Float;
+use std::ops::{Add, AddAssign, Mul, Sub};
+
+impl<T, const N: usize> MatrixOperations<[[T; N]; N], [T; N], T> for [[T; N]; N]
+where
+	T: Add<T, Output = T> + Sub<T, Output = T> + Mul<T, Output = T> + AddAssign + Copy,
+{
+	type Output = T;
+	type MatrixOutput = [[T; N]; N];
+	type VectorOutput = [T; N];
+
+	fn try_matrix_add_matrix(&self, rhs: &[[T; N]; N]) -> Result<[[T; N]; N], MatrixError> {
+		if N == 0 {
+			return Err(MatrixError::EmptyOperand);
+		}
+		Ok(std::array::from_fn(|row| {
+			std::array::from_fn(|column| self[row][column] + rhs[row][column])
+		}))
+	}
+
+	#[allow(clippy::eq_op)]
+	fn try_matrix_mult_matrix(&self, rhs: &[[T; N]; N]) -> Result<[[T; N]; N], MatrixError> {
+		if N == 0 {
+			return Err(MatrixError::EmptyOperand);
+		}
+		Ok(std::array::from_fn(|row| {
+			std::array::from_fn(|column| {
+				let mut temp = self[0][0] - self[0][0];
+				for index in 0..N {
+					temp += self[row][index] * rhs[index][column];
+				}
+				temp
+			})
+		}))
+	}
+
+	#[allow(clippy::eq_op)]
+	fn try_matrix_mult_point(&self, rhs: &[T; N]) -> Result<[T; N], MatrixError> {
+		if N == 0 {
+			return Err(MatrixError::EmptyOperand);
+		}
+		Ok(std::array::from_fn(|row| {
+			let mut temp = self[0][0] - self[0][0];
+			for column in 0..N {
+				temp += self[row][column] * rhs[column];
+			}
+			temp
+		}))
+	}
+	fn try_scaler_mult_matrix(&self, rhs: T) -> Result<[[T; N]; N], MatrixError> {
+		if N == 0 {
+			return Err(MatrixError::EmptyOperand);
+		}
+		Ok(std::array::from_fn(|row| {
+			std::array::from_fn(|column| self[row][column] * rhs)
+		}))
+	}
+	fn try_transpose(&self) -> Result<[[T; N]; N], MatrixError> {
+		if N == 0 {
+			return Err(MatrixError::EmptyOperand);
+		}
+		Ok(std::array::from_fn(|row| {
+			std::array::from_fn(|column| self[column][row])
+		}))
+	}
+
+	#[allow(clippy::eq_op)]
+	#[allow(clippy::needless_range_loop)]
+	fn try_trace(&self) -> Result<T, MatrixError> {
+		if N == 0 {
+			return Err(MatrixError::EmptyOperand);
+		}
+		let mut sum = self[0][0] - self[0][0];
+		for index in 0..N {
+			sum += self[index][index];
+		}
+		Ok(sum)
+	}
+	fn try_determinant(&self) -> Result<T, MatrixError>
+	where
+		T: Float,
+	{
+		if N == 0 {
+			return Err(MatrixError::EmptyOperand);
+		}
+		Ok(square_determinant(*self, N))
+	}
+	fn try_solve(&self, rhs: &[T; N]) -> Result<[T; N], MatrixError>
+	where
+		T: Float,
+	{
+		let matrix: Vec<Vec<T>> = self.iter().map(|row| row.to_vec()).collect();
+		let solution = solve_system(&matrix, rhs)?;
+		Ok(std::array::from_fn(|index| solution[index]))
+	}
+}
+
+#[cfg(test)]
+mod test_arr_matrix_ops {
+	use crate::transformations::{rotation_x, scale_3d, translation_3d};
+	use crate::{Matrix2D, Matrix3D, MatrixError, MatrixOperations, Point3D, I3, I4};
+
+	#[test]
+	fn test_arr_matrix_mult() {
+		let translate: Matrix3D = [
+			[1.0, 0.0, 0.0, 2.0],
+			[0.0, 1.0, 0.0, 3.0],
+			[0.0, 0.0, 1.0, 4.0],
+			[0.0, 0.0, 0.0, 1.0],
+		];
+		let point: Point3D = [1.0, 1.0, 1.0, 1.0];
+
+		assert_eq!([3.0, 4.0, 5.0, 1.0], translate.matrix_mult_point(&point));
+		assert_eq!(translate, I4.matrix_mult_matrix(&translate));
+		assert_eq!(
+			[
+				[1.0, 0.0, 0.0, 4.0],
+				[0.0, 1.0, 0.0, 6.0],
+				[0.0, 0.0, 1.0, 8.0],
+				[0.0, 0.0, 0.0, 1.0],
+			],
+			translate.matrix_mult_matrix(&translate)
+		);
+	}
+	#[test]
+	fn test_arr_matrix_add_scale() {
+		let matrix_a: Matrix2D = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]];
+
+		assert_eq!(
+			[[2.0, 2.0, 3.0], [4.0, 6.0, 6.0], [7.0, 8.0, 10.0]],
+			matrix_a.matrix_add_matrix(&I3)
+		);
+		assert_eq!(
+			[[2.0, 4.0, 6.0], [8.0, 10.0, 12.0], [14.0, 16.0, 18.0]],
+			matrix_a.scaler_mult_matrix(2.0)
+		);
+	}
+	#[test]
+	fn test_arr_transpose_trace_determinant() {
+		let matrix_a: Matrix2D = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 10.0]];
+		let transform: Matrix3D = translation_3d(1.0, 2.0, 3.0)
+			.matrix_mult_matrix(&rotation_x(0.4))
+			.matrix_mult_matrix(&scale_3d(2.0, 3.0, 4.0));
+
+		assert_eq!(
+			[[1.0, 4.0, 7.0], [2.0, 5.0, 8.0], [3.0, 6.0, 10.0]],
+			matrix_a.transpose()
+		);
+		assert_eq!(16.0, matrix_a.trace());
+		assert_eq!(4.0, I4.trace());
+		assert!((matrix_a.determinant() + 3.0).abs() < 1e-12);
+		assert!((transform.determinant() - 24.0).abs() < 1e-9);
+		assert_eq!(transform.determinant(), transform.transpose().determinant());
+	}
+	#[test]
+	fn test_arr_solve() {
+		let transform: Matrix3D = translation_3d(1.0, 2.0, 3.0)
+			.matrix_mult_matrix(&rotation_x(0.4))
+			.matrix_mult_matrix(&scale_3d(2.0, 3.0, 4.0));
+		let point: Point3D = [1.0, -2.0, 0.5, 1.0];
+		let singular: Matrix2D = [[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 0.0, 1.0]];
+
+		let result = transform.solve(&transform.matrix_mult_point(&point));
+		for (expected, result) in point.iter().zip(result.iter()) {
+			assert!((expected - result).abs() < 1e-9);
+		}
+		assert_eq!(Ok([1.0, 2.0, 3.0]), I3.try_solve(&[1.0, 2.0, 3.0]));
+		assert_eq!(
+			Err(MatrixError::Singular),
+			singular.try_solve(&[1.0, 2.0, 3.0])
+		);
+	}
+}
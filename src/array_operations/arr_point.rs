@@ -0,0 +1,45 @@
+use crate::{MatrixError, PointOperations};
+use std::ops::{Add, Mul, Sub};
+
+impl<T, const N: usize> PointOperations<[T; N], T> for [T; N]
+where
+	T: Mul<T, Output = T> + Sub<T, Output = T> + Add<T, Output = T> + Copy,
+{
+	type Output = T;
+	type VectorOutput = [T; N];
+
+	fn try_point_mult_scaler(&self, rhs: T) -> Result<[T; N], MatrixError> {
+		if N == 0 {
+			return Err(MatrixError::EmptyOperand);
+		}
+		Ok(std::array::from_fn(|index| self[index] * rhs))
+	}
+	fn try_point_minus_point(&self, rhs: &[T; N]) -> Result<[T; N], MatrixError> {
+		if N == 0 {
+			return Err(MatrixError::EmptyOperand);
+		}
+		Ok(std::array::from_fn(|index| self[index] - rhs[index]))
+	}
+	fn try_point_plus_vector(&self, rhs: &[T; N]) -> Result<[T; N], MatrixError> {
+		if N == 0 {
+			return Err(MatrixError::EmptyOperand);
+		}
+		Ok(std::array::from_fn(|index| self[index] + rhs[index]))
+	}
+}
+
+#[cfg(test)]
+mod test_arr_point {
+	use crate::{Point2D, PointOperations};
+
+	#[test]
+	fn test_arr_point_operations() {
+		let point_a: Point2D = [3.0, 2.0, 1.0];
+		let point_b: Point2D = [1.0, 1.0, 1.0];
+		let vec_a: Point2D = [0.5, 0.5, 0.0];
+
+		assert_eq!([2.0, 1.0, 0.0], point_a.point_minus_point(&point_b));
+		assert_eq!([3.5, 2.5, 1.0], point_a.point_plus_vector(&vec_a));
+		assert_eq!([6.0, 4.0, 2.0], point_a.point_mult_scaler(2.0));
+	}
+}
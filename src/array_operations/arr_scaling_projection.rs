@@ -0,0 +1,71 @@
+use crate::{MatrixError, VecScalingProjection, VectorOps};
+use num::Float;
+use std::ops::{AddAssign, Mul, SubAssign};
+
+impl<T, const N: usize> VecScalingProjection<[T; N]> for [T; N]
+where
+	T: AddAssign + SubAssign + Mul<T, Output = T> + Float,
+{
+	type Output = T;
+	type VectorOutput = [T; N];
+
+	fn try_magnitude(&self) -> Result<T, MatrixError> {
+		self.as_slice().try_magnitude()
+	}
+	fn try_vec_scalar_components(&self, rhs: &[T; N]) -> Result<T, MatrixError> {
+		self.as_slice().try_vec_scalar_components(rhs.as_slice())
+	}
+	fn try_vec_projection(&self, rhs: &[T; N]) -> Result<[T; N], MatrixError> {
+		let dot = self.try_dot(rhs)?;
+		let rhs_mag: T = rhs.try_magnitude()?;
+		if rhs_mag == T::zero() {
+			return Err(MatrixError::ZeroMagnitude);
+		}
+		rhs.try_vec_scal(dot / (rhs_mag * rhs_mag))
+	}
+	fn try_normalize(&self) -> Result<[T; N], MatrixError> {
+		let magnitude: T = self.try_magnitude()?;
+		if magnitude == T::zero() {
+			return Err(MatrixError::ZeroMagnitude);
+		}
+		self.try_vec_scal(T::one() / magnitude)
+	}
+	fn try_distance(&self, rhs: &[T; N]) -> Result<T, MatrixError> {
+		self.as_slice().try_distance(rhs.as_slice())
+	}
+	fn try_angle(&self, rhs: &[T; N]) -> Result<T, MatrixError> {
+		self.as_slice().try_angle(rhs.as_slice())
+	}
+}
+
+#[cfg(test)]
+mod test_arr_scaling_projection {
+	use crate::{Fsize, MatrixError, VecScalingProjection};
+
+	#[test]
+	fn test_arr_scaling_projection() {
+		let vec_1: [Fsize; 2] = [3.0, 4.0];
+		let vec_2: [Fsize; 2] = [1.0, 0.0];
+		let zero: [Fsize; 2] = [0.0, 0.0];
+
+		assert_eq!(5.0, vec_1.magnitude());
+		assert_eq!(3.0, vec_1.vec_scalar_components(&vec_2));
+		assert_eq!([3.0, 0.0], vec_1.vec_projection(&vec_2));
+		assert_eq!(
+			Err(MatrixError::ZeroMagnitude),
+			vec_1.try_vec_projection(&zero)
+		);
+	}
+	#[test]
+	fn test_arr_normalize_distance_angle() {
+		let vec_1: [Fsize; 2] = [3.0, 4.0];
+		let vec_2: [Fsize; 2] = [1.0, 0.0];
+		let zero: [Fsize; 2] = [0.0, 0.0];
+
+		assert_eq!([0.0, -1.0], [0.0, -2.0].normalize());
+		assert_eq!(Err(MatrixError::ZeroMagnitude), zero.try_normalize());
+		assert_eq!(5.0, vec_1.distance(&zero));
+		assert!((vec_1.angle(&vec_2) - (0.6 as Fsize).acos()).abs() < 1e-12);
+		assert_eq!(Err(MatrixError::ZeroMagnitude), vec_1.try_angle(&zero));
+	}
+}
@@ -0,0 +1,71 @@
+use crate::{MatrixError, VectorOps};
+use num::Zero;
+use std::ops::{Add, AddAssign, Mul, Sub};
+
+impl<T, const N: usize> VectorOps<[T; N], T> for [T; N]
+where
+	T: Add<T, Output = T> + Mul<T, Output = T> + Sub<T, Output = T> + AddAssign + Zero + Copy,
+{
+	type Output = T;
+	type VectorOutput = [T; N];
+
+	fn try_vec_scal(&self, scal: T) -> Result<[T; N], MatrixError> {
+		Ok(std::array::from_fn(|index| self[index] * scal))
+	}
+	fn try_vec_add(&self, rhs: &[T; N]) -> Result<[T; N], MatrixError> {
+		if N == 0 {
+			return Err(MatrixError::EmptyOperand);
+		}
+		Ok(std::array::from_fn(|index| self[index] + rhs[index]))
+	}
+	fn try_dot(&self, rhs: &[T; N]) -> Result<T, MatrixError> {
+		self.as_slice().try_dot(rhs.as_slice())
+	}
+	fn try_cross(&self, rhs: &[T; N]) -> Result<[T; N], MatrixError> {
+		let result = self.as_slice().try_cross(rhs.as_slice())?;
+		Ok(std::array::from_fn(|index| result[index]))
+	}
+}
+
+#[cfg(test)]
+mod test_arr_vector_ops {
+	use crate::{Fsize, MatrixError, Point3D, VectorOps};
+
+	#[test]
+	fn test_arr_vector_ops() {
+		let point_a: Point3D = [1.0, 2.0, 3.0, 1.0];
+		let point_b: Point3D = [3.0, 4.0, 4.0, 0.0];
+		let empty: [Fsize; 0] = [];
+
+		assert_eq!([4.0, 6.0, 7.0, 1.0], point_a.vec_add(&point_b));
+		assert_eq!([2.0, 4.0, 6.0, 2.0], point_a.vec_scal(2.0));
+		assert_eq!(23.0, point_a.dot(&point_b));
+		assert_eq!(Err(MatrixError::EmptyOperand), empty.try_dot(&empty));
+		assert_eq!(empty, empty.vec_scal(2.0));
+	}
+	#[test]
+	fn test_arr_cross() {
+		let x_axis: Point3D = [1.0, 0.0, 0.0, 0.0];
+		let y_axis: Point3D = [0.0, 1.0, 0.0, 0.0];
+		let vec_a: [Fsize; 3] = [1.0, 2.0, 3.0];
+		let vec_b: [Fsize; 3] = [4.0, 5.0, 6.0];
+		let plane: [Fsize; 2] = [1.0, 2.0];
+
+		assert_eq!([0.0, 0.0, 1.0, 0.0], x_axis.cross(&y_axis));
+		assert_eq!([-3.0, 6.0, -3.0], vec_a.cross(&vec_b));
+		assert_eq!(
+			Err(MatrixError::DimensionMismatch {
+				lhs: (2, 1),
+				rhs: (3, 1)
+			}),
+			plane.try_cross(&plane)
+		);
+	}
+	#[test]
+	fn test_slice_vector_ops() {
+		let vec_a: Vec<Fsize> = vec![1.0, 2.0, 3.0, 4.0];
+
+		assert_eq!(vec![2.0, 4.0], vec_a[..2].vec_add(&vec_a[..2]));
+		assert_eq!(13.0, vec_a[1..3].dot(&vec_a[1..3]));
+	}
+}
@@ -0,0 +1,6 @@
+mod arr_linear_operator;
+mod arr_matrix_inverse;
+mod arr_matrix_ops;
+mod arr_point;
+mod arr_scaling_projection;
+mod arr_vector_ops;
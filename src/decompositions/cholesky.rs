@@ -0,0 +1,230 @@
+use super::solve_lower_triangular;
+use crate::{symmetric_size, MatrixError};
+use num::Float;
+
+/// Cholesky factorization, `A = L·Lᵀ`, of a symmetric positive definite
+/// matrix.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cholesky<T> {
+	l: Vec<Vec<T>>,
+}
+
+/// Square root free factorization, `A = L·D·Lᵀ`, of a symmetric positive
+/// definite matrix, where `L` has a unit diagonal and `D` is diagonal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ldlt<T> {
+	l: Vec<Vec<T>>,
+	d: Vec<T>,
+}
+
+/// Factorizes `matrix` as `L·Lᵀ`, see [`Cholesky::new`].
+pub fn cholesky<T: Float>(matrix: &[Vec<T>]) -> Result<Cholesky<T>, MatrixError> {
+	Cholesky::new(matrix)
+}
+
+/// Factorizes `matrix` as `L·D·Lᵀ`, see [`Ldlt::new`].
+pub fn ldlt<T: Float>(matrix: &[Vec<T>]) -> Result<Ldlt<T>, MatrixError> {
+	Ldlt::new(matrix)
+}
+
+impl<T: Float> Cholesky<T> {
+	/// Fails with `NotSymmetric`, or `NotPositiveDefinite` when a pivot is not
+	/// positive.
+	pub fn new(matrix: &[Vec<T>]) -> Result<Self, MatrixError> {
+		let size = symmetric_size(matrix)?;
+		let mut l = vec![vec![T::zero(); size]; size];
+		for column in 0..size {
+			let pivot = l[column][..column]
+				.iter()
+				.fold(matrix[column][column], |sum, value| sum - *value * *value);
+			if pivot <= T::zero() || pivot.is_nan() {
+				return Err(MatrixError::NotPositiveDefinite);
+			}
+			let diagonal = pivot.sqrt();
+			l[column][column] = diagonal;
+			for row in column + 1..size {
+				let sum = l[row][..column]
+					.iter()
+					.zip(l[column][..column].iter())
+					.fold(matrix[row][column], |sum, (lhs, rhs)| sum - *lhs * *rhs);
+				l[row][column] = sum / diagonal;
+			}
+		}
+		Ok(Cholesky { l })
+	}
+
+	/// The lower triangular factor.
+	pub fn l(&self) -> &[Vec<T>] {
+		&self.l
+	}
+
+	/// Solves `A·x = rhs`.
+	pub fn solve(&self, rhs: &[T]) -> Result<Vec<T>, MatrixError> {
+		let y = solve_lower_triangular(&self.l, rhs)?;
+		Ok(solve_lower_transpose(&self.l, y))
+	}
+
+	pub fn determinant(&self) -> T {
+		self.l
+			.iter()
+			.enumerate()
+			.fold(T::one(), |product, (index, row)| {
+				product * row[index] * row[index]
+			})
+	}
+
+	/// The natural logarithm of the determinant, which stays finite where the
+	/// determinant itself would overflow or underflow.
+	pub fn log_determinant(&self) -> T {
+		let two = T::one() + T::one();
+		self.l
+			.iter()
+			.enumerate()
+			.fold(T::zero(), |sum, (index, row)| sum + two * row[index].ln())
+	}
+}
+
+impl<T: Float> Ldlt<T> {
+	/// Fails with `NotSymmetric`, or `NotPositiveDefinite` when an entry of
+	/// `D` is not positive.
+	pub fn new(matrix: &[Vec<T>]) -> Result<Self, MatrixError> {
+		let size = symmetric_size(matrix)?;
+		let mut l = vec![vec![T::zero(); size]; size];
+		let mut d = vec![T::zero(); size];
+		for column in 0..size {
+			let pivot = l[column][..column]
+				.iter()
+				.zip(d.iter())
+				.fold(matrix[column][column], |sum, (value, d)| {
+					sum - *value * *value * *d
+				});
+			if pivot <= T::zero() || pivot.is_nan() {
+				return Err(MatrixError::NotPositiveDefinite);
+			}
+			d[column] = pivot;
+			l[column][column] = T::one();
+			for row in column + 1..size {
+				let sum = l[row][..column]
+					.iter()
+					.zip(l[column][..column].iter())
+					.zip(d.iter())
+					.fold(matrix[row][column], |sum, ((lhs, rhs), d)| {
+						sum - *lhs * *rhs * *d
+					});
+				l[row][column] = sum / pivot;
+			}
+		}
+		Ok(Ldlt { l, d })
+	}
+
+	/// The unit lower triangular factor.
+	pub fn l(&self) -> &[Vec<T>] {
+		&self.l
+	}
+
+	/// The diagonal of `D`.
+	pub fn d(&self) -> &[T] {
+		&self.d
+	}
+
+	/// Solves `A·x = rhs`.
+	pub fn solve(&self, rhs: &[T]) -> Result<Vec<T>, MatrixError> {
+		let mut y = solve_lower_triangular(&self.l, rhs)?;
+		y.iter_mut()
+			.zip(self.d.iter())
+			.for_each(|(value, d)| *value = *value / *d);
+		Ok(solve_lower_transpose(&self.l, y))
+	}
+
+	pub fn determinant(&self) -> T {
+		self.d.iter().fold(T::one(), |product, d| product * *d)
+	}
+
+	/// The natural logarithm of the determinant, which stays finite where the
+	/// determinant itself would overflow or underflow.
+	pub fn log_determinant(&self) -> T {
+		self.d.iter().fold(T::zero(), |sum, d| sum + d.ln())
+	}
+}
+
+//solves Lᵀ·x = y by back substitution without forming Lᵀ, for a lower
+//triangular `l` with a non zero diagonal
+fn solve_lower_transpose<T: Float>(l: &[Vec<T>], mut y: Vec<T>) -> Vec<T> {
+	for row in (0..y.len()).rev() {
+		let sum = l[row + 1..]
+			.iter()
+			.zip(y[row + 1..].iter())
+			.fold(y[row], |sum, (l_row, x)| sum - l_row[row] * *x);
+		y[row] = sum / l[row][row];
+	}
+	y
+}
+
+#[cfg(test)]
+mod test_cholesky {
+	use super::{cholesky, ldlt};
+	use crate::{Fsize, MatrixError, MatrixOperations};
+
+	fn spd() -> Vec<Vec<Fsize>> {
+		vec![
+			vec![4.0, 12.0, -16.0],
+			vec![12.0, 37.0, -43.0],
+			vec![-16.0, -43.0, 98.0],
+		]
+	}
+	fn assert_close(expected: &[Fsize], result: &[Fsize]) {
+		for (expected, result) in expected.iter().zip(result.iter()) {
+			assert!((expected - result).abs() < 1e-9);
+		}
+	}
+
+	#[test]
+	fn test_cholesky() {
+		let matrix = spd();
+		let factor = cholesky(&matrix).unwrap();
+		let l = factor.l().to_vec();
+
+		assert_eq!(
+			vec![
+				vec![2.0, 0.0, 0.0],
+				vec![6.0, 1.0, 0.0],
+				vec![-8.0, 5.0, 3.0]
+			],
+			l
+		);
+		assert_eq!(matrix, l.matrix_mult_matrix(&l.transpose()));
+		let x = factor.solve(&[1.0, 2.0, 3.0]).unwrap();
+		assert_close(&[1.0, 2.0, 3.0], &matrix.matrix_mult_point(&x));
+		assert!((factor.determinant() - 36.0).abs() < 1e-9);
+		assert!((factor.log_determinant() - (36.0 as Fsize).ln()).abs() < 1e-9);
+	}
+	#[test]
+	fn test_ldlt() {
+		let matrix = spd();
+		let factor = ldlt(&matrix).unwrap();
+
+		assert_eq!(&[4.0, 1.0, 9.0], factor.d());
+		assert_eq!(
+			vec![
+				vec![1.0, 0.0, 0.0],
+				vec![3.0, 1.0, 0.0],
+				vec![-4.0, 5.0, 1.0]
+			],
+			factor.l().to_vec()
+		);
+		let x = factor.solve(&[1.0, 2.0, 3.0]).unwrap();
+		assert_close(&[1.0, 2.0, 3.0], &matrix.matrix_mult_point(&x));
+		assert!((factor.determinant() - 36.0).abs() < 1e-9);
+		assert!((factor.log_determinant() - (36.0 as Fsize).ln()).abs() < 1e-9);
+	}
+	#[test]
+	fn test_not_positive_definite() {
+		let indefinite: Vec<Vec<Fsize>> = vec![vec![1.0, 2.0], vec![2.0, 1.0]];
+		assert_eq!(Err(MatrixError::NotPositiveDefinite), cholesky(&indefinite));
+		assert_eq!(Err(MatrixError::NotPositiveDefinite), ldlt(&indefinite));
+		assert_eq!(
+			Err(MatrixError::NotSymmetric { row: 0, column: 1 }),
+			cholesky(&[vec![1.0, 2.0], vec![0.0, 1.0]])
+		);
+	}
+}
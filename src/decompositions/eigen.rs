@@ -0,0 +1,204 @@
+use super::RealSchur;
+use crate::MatrixError;
+use num::{Complex, Float, Zero};
+
+/// Eigenvalues and eigenvectors of a general real square matrix, which come
+/// in complex conjugate pairs when they are not real.
+///
+/// `eigenvectors()[i]` is the eigenvector paired with `eigenvalues()[i]`,
+/// scaled to unit length. Defective matrices have fewer independent
+/// eigenvectors than eigenvalues, so some returned vectors are then close to
+/// parallel.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Eigen<T> {
+	eigenvalues: Vec<Complex<T>>,
+	eigenvectors: Vec<Vec<Complex<T>>>,
+}
+
+impl<T: Float> Eigen<T> {
+	pub fn new(matrix: &[Vec<T>]) -> Result<Self, MatrixError> {
+		Ok(Eigen::from_schur(&RealSchur::new(matrix)?))
+	}
+
+	/// Extracts the eigenvectors from an existing Schur decomposition by back
+	/// substitution on `T`.
+	pub fn from_schur(schur: &RealSchur<T>) -> Self {
+		let eigenvalues = schur.eigenvalues().to_vec();
+		let eigenvectors = eigenvalues
+			.iter()
+			.enumerate()
+			.map(|(index, eigenvalue)| eigenvector(schur, index, *eigenvalue))
+			.collect();
+		Eigen {
+			eigenvalues,
+			eigenvectors,
+		}
+	}
+
+	pub fn eigenvalues(&self) -> &[Complex<T>] {
+		&self.eigenvalues
+	}
+
+	pub fn eigenvectors(&self) -> &[Vec<Complex<T>>] {
+		&self.eigenvectors
+	}
+}
+
+//solves (T - λ·I)·x = 0 upwards from the block holding `index`, then maps x
+//back through Q
+fn eigenvector<T: Float>(
+	schur: &RealSchur<T>,
+	index: usize,
+	eigenvalue: Complex<T>,
+) -> Vec<Complex<T>> {
+	let t = schur.t();
+	let size = t.len();
+	let norm = t
+		.iter()
+		.flatten()
+		.fold(T::zero(), |sum, value| sum + value.abs());
+	// stands in for exact zero pivots of repeated eigenvalues
+	let small = Complex::from(T::epsilon() * norm.max(T::one()));
+	let pivot = |value: Complex<T>| if value.is_zero() { small } else { value };
+	let shifted = |row: usize| Complex::from(t[row][row]) - eigenvalue;
+	let tail = |row: usize, x: &[Complex<T>], from: usize| {
+		x[from..]
+			.iter()
+			.zip(t[row][from..].iter())
+			.fold(Complex::zero(), |sum: Complex<T>, (x, t)| sum - *x * *t)
+	};
+
+	let mut x = vec![Complex::zero(); size];
+	x[index] = Complex::from(T::one());
+	let mut top = index;
+	if index > 0 && t[index][index - 1] != T::zero() {
+		x[index - 1] = -Complex::from(t[index - 1][index]) / shifted(index - 1);
+		top = index - 1;
+	} else if index + 1 < size && t[index + 1][index] != T::zero() {
+		x[index + 1] = -Complex::from(t[index + 1][index]) / shifted(index + 1);
+	}
+
+	let mut row = top;
+	while row > 0 {
+		row -= 1;
+		if row > 0 && t[row][row - 1] != T::zero() {
+			// a 2x2 block on rows row - 1 and row, solved with Cramer's rule
+			let upper = row - 1;
+			let rhs_upper = tail(upper, &x, row + 1);
+			let rhs_lower = tail(row, &x, row + 1);
+			let (a, b) = (shifted(upper), Complex::from(t[upper][row]));
+			let (c, d) = (Complex::from(t[row][upper]), shifted(row));
+			let determinant = pivot(a * d - b * c);
+			x[upper] = (rhs_upper * d - b * rhs_lower) / determinant;
+			x[row] = (a * rhs_lower - c * rhs_upper) / determinant;
+			row = upper;
+		} else {
+			x[row] = tail(row, &x, row + 1) / pivot(shifted(row));
+		}
+	}
+
+	let vector: Vec<Complex<T>> = schur
+		.q()
+		.iter()
+		.map(|q_row| {
+			q_row
+				.iter()
+				.zip(x.iter())
+				.fold(Complex::zero(), |sum, (q, x)| sum + *x * *q)
+		})
+		.collect();
+	let length = vector
+		.iter()
+		.fold(T::zero(), |sum, value| sum + value.norm_sqr())
+		.sqrt();
+	vector.into_iter().map(|value| value / length).collect()
+}
+
+#[cfg(test)]
+mod test_eigen {
+	use super::Eigen;
+	use crate::decompositions::RealSchur;
+	use crate::{Fsize, MatrixOperations};
+	use num::Complex;
+
+	fn assert_close(expected: Complex<Fsize>, result: Complex<Fsize>) {
+		assert!((expected - result).norm() < 1e-9);
+	}
+	fn assert_eigenpairs(matrix: &[Vec<Fsize>], eigen: &Eigen<Fsize>) {
+		for (value, vector) in eigen.eigenvalues().iter().zip(eigen.eigenvectors().iter()) {
+			for (row, element) in matrix.iter().zip(vector.iter()) {
+				let product = row
+					.iter()
+					.zip(vector.iter())
+					.fold(Complex::new(0.0, 0.0), |sum, (a, v)| sum + v * a);
+				assert_close(value * element, product);
+			}
+		}
+	}
+
+	#[test]
+	fn test_real_schur() {
+		let matrix: Vec<Vec<Fsize>> = vec![
+			vec![4.0, -2.0, 1.0, 3.0, 0.5],
+			vec![3.0, -1.0, 2.0, 0.0, 1.0],
+			vec![1.0, 5.0, 2.0, -3.0, -2.0],
+			vec![2.0, 0.0, -1.0, 1.0, 4.0],
+			vec![-1.0, 2.0, 0.0, 6.0, -3.0],
+		];
+		let schur = RealSchur::new(&matrix).unwrap();
+		let t = schur.t().to_vec();
+		let q = schur.q().to_vec();
+
+		let result = q.matrix_mult_matrix(&t).matrix_mult_matrix(&q.transpose());
+		for (expected_row, result_row) in matrix.iter().zip(result.iter()) {
+			for (expected, result) in expected_row.iter().zip(result_row.iter()) {
+				assert!((expected - result).abs() < 1e-9);
+			}
+		}
+		for (row, vec_row) in t.iter().enumerate().skip(2) {
+			assert!(vec_row[..row - 1].iter().all(|value| *value == 0.0));
+		}
+		let sum: Complex<Fsize> = schur.eigenvalues().iter().sum();
+		let product: Complex<Fsize> = schur.eigenvalues().iter().product();
+		assert_close(Complex::new(matrix.trace(), 0.0), sum);
+		assert_close(Complex::new(matrix.determinant(), 0.0), product);
+	}
+	#[test]
+	fn test_eigen_complex() {
+		// a rotation by 90 degrees about Z followed by a scaling of 2 along Z
+		let matrix: Vec<Vec<Fsize>> = vec![
+			vec![0.0, -1.0, 0.0],
+			vec![1.0, 0.0, 0.0],
+			vec![0.0, 0.0, 2.0],
+		];
+		let eigen = Eigen::new(&matrix).unwrap();
+		let mut values = eigen.eigenvalues().to_vec();
+		values.sort_by(|lhs, rhs| lhs.im.partial_cmp(&rhs.im).unwrap());
+
+		assert_close(Complex::new(0.0, -1.0), values[0]);
+		assert_close(Complex::new(2.0, 0.0), values[1]);
+		assert_close(Complex::new(0.0, 1.0), values[2]);
+		assert_eigenpairs(&matrix, &eigen);
+	}
+	#[test]
+	fn test_eigen_general() {
+		let matrix: Vec<Vec<Fsize>> = vec![
+			vec![4.0, -2.0, 1.0, 3.0],
+			vec![3.0, -1.0, 2.0, 0.0],
+			vec![1.0, 5.0, 2.0, -3.0],
+			vec![2.0, 0.0, -1.0, 1.0],
+		];
+		assert_eigenpairs(&matrix, &Eigen::new(&matrix).unwrap());
+
+		// repeated and zero eigenvalues still produce finite unit vectors
+		let defective: Vec<Vec<Fsize>> = vec![vec![1.0, 1.0], vec![0.0, 1.0]];
+		for matrix in [defective, vec![vec![0.0; 3]; 3]].iter() {
+			let eigen = Eigen::new(matrix).unwrap();
+			for vector in eigen.eigenvectors().iter() {
+				assert!(vector
+					.iter()
+					.all(|value| value.re.is_finite() && value.im.is_finite()));
+			}
+		}
+	}
+}
@@ -0,0 +1,105 @@
+use super::qr::reflect;
+use crate::{square_size, MatrixError};
+use num::Float;
+
+/// Orthogonal reduction to upper Hessenberg form, `A = Q·H·Qᵀ`, where `H` is
+/// zero below its first subdiagonal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hessenberg<T> {
+	h: Vec<Vec<T>>,
+	q: Vec<Vec<T>>,
+}
+
+impl<T: Float> Hessenberg<T> {
+	pub fn new(matrix: &[Vec<T>]) -> Result<Self, MatrixError> {
+		let size = square_size(matrix)?;
+		let mut h = matrix.to_vec();
+		let mut q: Vec<Vec<T>> = (0..size)
+			.map(|row| {
+				(0..size)
+					.map(|column| if row == column { T::one() } else { T::zero() })
+					.collect()
+			})
+			.collect();
+
+		for k in 0..size.saturating_sub(2) {
+			let norm = h[k + 1..]
+				.iter()
+				.fold(T::zero(), |sum, row| sum + row[k] * row[k])
+				.sqrt();
+			if norm == T::zero() {
+				continue;
+			}
+			let mut v: Vec<T> = h[k + 1..].iter().map(|row| row[k]).collect();
+			// reflect onto -sign(x0)·|x| so the subtraction below never cancels
+			let alpha = if v[0] > T::zero() { -norm } else { norm };
+			v[0] = v[0] - alpha;
+			let v_norm = v
+				.iter()
+				.fold(T::zero(), |sum, value| sum + *value * *value)
+				.sqrt();
+			v.iter_mut().for_each(|value| *value = *value / v_norm);
+
+			// H = P·H·P and Q = Q·P with P = I - 2·v·vᵀ acting on k + 1 onwards
+			for column in 0..size {
+				let projection = v
+					.iter()
+					.zip(h[k + 1..].iter())
+					.fold(T::zero(), |sum, (v, row)| sum + *v * row[column]);
+				for (v, row) in v.iter().zip(h[k + 1..].iter_mut()) {
+					row[column] = row[column] - (projection + projection) * *v;
+				}
+			}
+			for row in h.iter_mut().chain(q.iter_mut()) {
+				reflect(&mut row[k + 1..], &v);
+			}
+			for row in h[k + 2..].iter_mut() {
+				row[k] = T::zero();
+			}
+		}
+		Ok(Hessenberg { h, q })
+	}
+
+	pub fn h(&self) -> &[Vec<T>] {
+		&self.h
+	}
+
+	/// The orthogonal factor.
+	pub fn q(&self) -> &[Vec<T>] {
+		&self.q
+	}
+
+	pub(crate) fn into_parts(self) -> (Vec<Vec<T>>, Vec<Vec<T>>) {
+		(self.h, self.q)
+	}
+}
+
+#[cfg(test)]
+mod test_hessenberg {
+	use super::Hessenberg;
+	use crate::{Fsize, MatrixOperations};
+
+	#[test]
+	fn test_hessenberg() {
+		let matrix: Vec<Vec<Fsize>> = vec![
+			vec![4.0, 1.0, -2.0, 2.0, 1.0],
+			vec![3.0, 2.0, 0.0, 1.0, -1.0],
+			vec![-2.0, 5.0, 3.0, -2.0, 0.0],
+			vec![2.0, 1.0, -2.0, -1.0, 4.0],
+			vec![0.5, 1.0, 7.0, 2.0, 3.0],
+		];
+		let hessenberg = Hessenberg::new(&matrix).unwrap();
+		let h = hessenberg.h().to_vec();
+		let q = hessenberg.q().to_vec();
+
+		let result = q.matrix_mult_matrix(&h).matrix_mult_matrix(&q.transpose());
+		for (expected_row, result_row) in matrix.iter().zip(result.iter()) {
+			for (expected, result) in expected_row.iter().zip(result_row.iter()) {
+				assert!((expected - result).abs() < 1e-9);
+			}
+		}
+		for (row, vec_row) in h.iter().enumerate().skip(2) {
+			assert!(vec_row[..row - 1].iter().all(|value| *value == 0.0));
+		}
+	}
+}
@@ -0,0 +1,271 @@
+use crate::{matrix_shape, square_size, MatrixError};
+use num::Float;
+
+/// LU factorization with partial pivoting, `P·A = L·U`, computed once and
+/// reused to solve any number of systems sharing `A`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LuDecomposition<T> {
+	//L below the diagonal, with an implicit unit diagonal, and U on and above it
+	lu: Vec<Vec<T>>,
+	permutation: Vec<usize>,
+	swap_count: usize,
+	//pivots at or below this, relative to the largest entry of A, are singular
+	tolerance: T,
+}
+
+impl<T: Float> LuDecomposition<T> {
+	/// Factorizes a square matrix. Singular matrices factorize successfully,
+	/// leaving a zero on the diagonal of U, but cannot be solved. Neither can
+	/// numerically singular ones, whose U has a diagonal entry no larger than
+	/// `n·ε` times the largest entry of `A`.
+	#[allow(clippy::needless_range_loop)]
+	pub fn new(matrix: &[Vec<T>]) -> Result<Self, MatrixError> {
+		let size = square_size(matrix)?;
+		let mut lu = matrix.to_vec();
+		let mut permutation: Vec<usize> = (0..size).collect();
+		let mut swap_count = 0;
+		let largest = matrix
+			.iter()
+			.flatten()
+			.fold(T::zero(), |max, value| max.max(value.abs()));
+		let tolerance = largest * T::epsilon() * T::from(size).unwrap();
+
+		for column in 0..size {
+			let mut pivot_row = column;
+			for row in column + 1..size {
+				if lu[row][column].abs() > lu[pivot_row][column].abs() {
+					pivot_row = row;
+				}
+			}
+			if pivot_row != column {
+				lu.swap(pivot_row, column);
+				permutation.swap(pivot_row, column);
+				swap_count += 1;
+			}
+			let pivot = lu[column][column];
+			if pivot == T::zero() {
+				continue;
+			}
+			for row in column + 1..size {
+				let factor = lu[row][column] / pivot;
+				lu[row][column] = factor;
+				for index in column + 1..size {
+					lu[row][index] = lu[row][index] - factor * lu[column][index];
+				}
+			}
+		}
+		Ok(LuDecomposition {
+			lu,
+			permutation,
+			swap_count,
+			tolerance,
+		})
+	}
+
+	/// The unit lower triangular factor.
+	pub fn l(&self) -> Vec<Vec<T>> {
+		self.triangle(|row, column| {
+			if row == column {
+				Some(T::one())
+			} else if column < row {
+				None
+			} else {
+				Some(T::zero())
+			}
+		})
+	}
+
+	/// The upper triangular factor.
+	pub fn u(&self) -> Vec<Vec<T>> {
+		self.triangle(
+			|row, column| {
+				if column >= row {
+					None
+				} else {
+					Some(T::zero())
+				}
+			},
+		)
+	}
+
+	/// Row `i` of `P·A` is row `permutation()[i]` of `A`.
+	pub fn permutation(&self) -> &[usize] {
+		&self.permutation
+	}
+
+	pub fn determinant(&self) -> T {
+		let mut determinant = if self.swap_count % 2 == 0 {
+			T::one()
+		} else {
+			-T::one()
+		};
+		for (index, row) in self.lu.iter().enumerate() {
+			determinant = determinant * row[index];
+		}
+		determinant
+	}
+
+	/// Solves `A·x = rhs`.
+	#[allow(clippy::needless_range_loop)]
+	pub fn solve(&self, rhs: &[T]) -> Result<Vec<T>, MatrixError> {
+		let size = self.lu.len();
+		if rhs.is_empty() {
+			return Err(MatrixError::EmptyOperand);
+		} else if rhs.len() != size {
+			return Err(MatrixError::DimensionMismatch {
+				lhs: (size, size),
+				rhs: (rhs.len(), 1),
+			});
+		}
+		if self
+			.lu
+			.iter()
+			.enumerate()
+			.any(|(index, row)| row[index].abs() <= self.tolerance)
+		{
+			return Err(MatrixError::Singular);
+		}
+
+		// forward substitution with L, then back substitution with U
+		let mut x: Vec<T> = self.permutation.iter().map(|&row| rhs[row]).collect();
+		for row in 0..size {
+			for column in 0..row {
+				x[row] = x[row] - self.lu[row][column] * x[column];
+			}
+		}
+		for row in (0..size).rev() {
+			for column in row + 1..size {
+				x[row] = x[row] - self.lu[row][column] * x[column];
+			}
+			x[row] = x[row] / self.lu[row][row];
+		}
+		Ok(x)
+	}
+
+	/// Solves `A·X = rhs` for every column of `rhs`.
+	pub fn solve_multiple(&self, rhs: &[Vec<T>]) -> Result<Vec<Vec<T>>, MatrixError> {
+		let size = self.lu.len();
+		let (rows, columns) = matrix_shape(rhs)?;
+		if rows != size {
+			return Err(MatrixError::DimensionMismatch {
+				lhs: (size, size),
+				rhs: (rows, columns),
+			});
+		}
+		let mut result = vec![vec![T::zero(); columns]; size];
+		for column in 0..columns {
+			let rhs_column: Vec<T> = rhs.iter().map(|row| row[column]).collect();
+			let solution = self.solve(&rhs_column)?;
+			for (row, value) in solution.into_iter().enumerate() {
+				result[row][column] = value;
+			}
+		}
+		Ok(result)
+	}
+
+	//copies the packed factors, replacing entries where `fill` returns a value
+	fn triangle<F>(&self, fill: F) -> Vec<Vec<T>>
+	where
+		F: Fn(usize, usize) -> Option<T>,
+	{
+		self.lu
+			.iter()
+			.enumerate()
+			.map(|(row, vec_row)| {
+				vec_row
+					.iter()
+					.enumerate()
+					.map(|(column, value)| fill(row, column).unwrap_or(*value))
+					.collect()
+			})
+			.collect()
+	}
+}
+
+#[cfg(test)]
+mod test_lu {
+	use super::LuDecomposition;
+	use crate::{Fsize, MatrixError, MatrixOperations};
+
+	fn assert_matrix_close(expected: &[Vec<Fsize>], result: &[Vec<Fsize>]) {
+		for (expected_row, result_row) in expected.iter().zip(result.iter()) {
+			for (expected, result) in expected_row.iter().zip(result_row.iter()) {
+				assert!((expected - result).abs() < 1e-9);
+			}
+		}
+	}
+
+	#[test]
+	fn test_lu_factors() {
+		let matrix: Vec<Vec<Fsize>> = vec![
+			vec![0.0, 2.0, 1.0, 3.0, 1.0],
+			vec![1.0, 0.0, 4.0, 1.0, 2.0],
+			vec![2.0, 1.0, 0.0, 5.0, 3.0],
+			vec![3.0, 1.0, 1.0, 0.0, 4.0],
+			vec![1.0, 1.0, 1.0, 1.0, 1.0],
+		];
+		let lu = LuDecomposition::new(&matrix).unwrap();
+		let permuted: Vec<Vec<Fsize>> = lu
+			.permutation()
+			.iter()
+			.map(|&row| matrix[row].clone())
+			.collect();
+
+		assert_matrix_close(&permuted, &lu.l().matrix_mult_matrix(&lu.u()));
+		assert!((lu.determinant() - matrix.determinant()).abs() < 1e-9);
+		for (row, vec_row) in lu.l().iter().enumerate() {
+			assert_eq!(1.0, vec_row[row]);
+			assert!(vec_row[row + 1..].iter().all(|value| *value == 0.0));
+		}
+	}
+	#[test]
+	fn test_lu_solve() {
+		let matrix: Vec<Vec<Fsize>> = vec![
+			vec![2.0, 1.0, -1.0],
+			vec![-3.0, -1.0, 2.0],
+			vec![-2.0, 1.0, 2.0],
+		];
+		let lu = LuDecomposition::new(&matrix).unwrap();
+
+		let x = lu.solve(&[8.0, -11.0, -3.0]).unwrap();
+		assert_matrix_close(&[vec![2.0, 3.0, -1.0]], &[x]);
+		let rhs: Vec<Vec<Fsize>> = vec![vec![8.0, 1.0], vec![-11.0, 0.0], vec![-3.0, 0.0]];
+		let solutions = lu.solve_multiple(&rhs).unwrap();
+		assert_matrix_close(&rhs, &matrix.matrix_mult_matrix(&solutions));
+		assert_eq!(
+			Err(MatrixError::DimensionMismatch {
+				lhs: (3, 3),
+				rhs: (2, 1)
+			}),
+			lu.solve(&[1.0, 2.0])
+		);
+	}
+	#[test]
+	fn test_lu_singular() {
+		let singular: Vec<Vec<Fsize>> = vec![vec![1.0, 2.0], vec![2.0, 4.0]];
+		let lu = LuDecomposition::new(&singular).unwrap();
+
+		assert_eq!(0.0, lu.determinant());
+		assert_eq!(Err(MatrixError::Singular), lu.solve(&[1.0, 2.0]));
+		// singular up to rounding, U ends up with a pivot around 1e-16
+		let nearly_singular: Vec<Vec<Fsize>> = vec![
+			vec![1.0, 2.0, 3.0],
+			vec![4.0, 5.0, 6.0],
+			vec![7.0, 8.0, 9.0],
+		];
+		let lu = LuDecomposition::new(&nearly_singular).unwrap();
+		assert!(lu
+			.u()
+			.iter()
+			.enumerate()
+			.all(|(row, vec_row)| vec_row[row] != 0.0));
+		assert_eq!(Err(MatrixError::Singular), lu.solve(&[1.0, 2.0, 3.0]));
+		assert_eq!(
+			Err(MatrixError::NotSquare {
+				rows: 1,
+				columns: 2
+			}),
+			LuDecomposition::new(&[vec![1.0, 2.0]])
+		);
+	}
+}
@@ -0,0 +1,21 @@
+mod cholesky;
+mod eigen;
+mod hessenberg;
+mod lu;
+mod qr;
+mod schur;
+mod solve;
+mod svd;
+mod symmetric_eigen;
+mod triangular;
+
+pub use cholesky::{cholesky, ldlt, Cholesky, Ldlt};
+pub use eigen::Eigen;
+pub use hessenberg::Hessenberg;
+pub use lu::LuDecomposition;
+pub use qr::{least_squares, LeastSquares, QrDecomposition};
+pub use schur::RealSchur;
+pub(crate) use solve::solve_system;
+pub use svd::Svd;
+pub use symmetric_eigen::SymmetricEigen;
+pub use triangular::{solve_lower_triangular, solve_upper_triangular};
@@ -0,0 +1,244 @@
+use crate::{matrix_shape, MatrixError};
+use num::Float;
+
+/// Householder QR factorization, `A = Q·R`, of a rectangular matrix.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QrDecomposition<T> {
+	//R above the diagonal, the parts below it are left over from the reflections
+	r: Vec<Vec<T>>,
+	//unit Householder vectors, the k-th acting on rows k onwards
+	reflectors: Vec<Vec<T>>,
+}
+
+/// The solution minimizing `|A·x - b|`, together with that minimal norm.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeastSquares<T> {
+	pub solution: Vec<T>,
+	pub residual_norm: T,
+}
+
+impl<T: Float> QrDecomposition<T> {
+	pub fn new(matrix: &[Vec<T>]) -> Result<Self, MatrixError> {
+		let (rows, columns) = matrix_shape(matrix)?;
+		let mut r = matrix.to_vec();
+		let mut reflectors = Vec::new();
+
+		for k in 0..columns.min(rows - 1) {
+			let norm = r[k..]
+				.iter()
+				.fold(T::zero(), |sum, row| sum + row[k] * row[k])
+				.sqrt();
+			let mut v: Vec<T> = r[k..].iter().map(|row| row[k]).collect();
+			// reflect onto -sign(x0)·|x| so the subtraction below never cancels
+			let alpha = if v[0] > T::zero() { -norm } else { norm };
+			v[0] = v[0] - alpha;
+			let v_norm = v
+				.iter()
+				.fold(T::zero(), |sum, value| sum + *value * *value)
+				.sqrt();
+			if v_norm == T::zero() {
+				reflectors.push(vec![T::zero(); rows - k]);
+				continue;
+			}
+			v.iter_mut().for_each(|value| *value = *value / v_norm);
+			for column in k..columns {
+				let projection = v
+					.iter()
+					.zip(r[k..].iter())
+					.fold(T::zero(), |sum, (v, row)| sum + *v * row[column]);
+				for (v, row) in v.iter().zip(r[k..].iter_mut()) {
+					row[column] = row[column] - (projection + projection) * *v;
+				}
+			}
+			reflectors.push(v);
+		}
+		Ok(QrDecomposition { r, reflectors })
+	}
+
+	/// The square orthogonal factor, with as many rows as `A`.
+	pub fn q(&self) -> Vec<Vec<T>> {
+		let rows = self.r.len();
+		let mut q: Vec<Vec<T>> = (0..rows)
+			.map(|row| {
+				(0..rows)
+					.map(|column| if row == column { T::one() } else { T::zero() })
+					.collect()
+			})
+			.collect();
+		// Q = H0·H1·...·Hk, applied to the rows of the identity
+		for (k, v) in self.reflectors.iter().enumerate() {
+			for row in q.iter_mut() {
+				reflect(&mut row[k..], v);
+			}
+		}
+		q
+	}
+
+	/// The upper triangular factor, with the same shape as `A`.
+	pub fn r(&self) -> Vec<Vec<T>> {
+		self.r
+			.iter()
+			.enumerate()
+			.map(|(row, vec_row)| {
+				vec_row
+					.iter()
+					.enumerate()
+					.map(|(column, value)| if column < row { T::zero() } else { *value })
+					.collect()
+			})
+			.collect()
+	}
+
+	/// Computes `Qᵀ·vector` without forming `Q`.
+	pub fn q_transpose_mult(&self, vector: &[T]) -> Result<Vec<T>, MatrixError> {
+		let rows = self.r.len();
+		if vector.is_empty() {
+			return Err(MatrixError::EmptyOperand);
+		} else if vector.len() != rows {
+			return Err(MatrixError::DimensionMismatch {
+				lhs: (rows, self.r[0].len()),
+				rhs: (vector.len(), 1),
+			});
+		}
+		let mut result = vector.to_vec();
+		for (k, v) in self.reflectors.iter().enumerate() {
+			reflect(&mut result[k..], v);
+		}
+		Ok(result)
+	}
+
+	/// Solves `A·x = rhs` in the least squares sense. `A` needs at least as
+	/// many rows as columns and full column rank.
+	pub fn least_squares(&self, rhs: &[T]) -> Result<LeastSquares<T>, MatrixError> {
+		let rows = self.r.len();
+		let columns = self.r[0].len();
+		if rows < columns {
+			return Err(MatrixError::Underdetermined { rows, columns });
+		}
+		let mut y = self.q_transpose_mult(rhs)?;
+
+		let largest =
+			(0..columns).fold(T::zero(), |max, index| max.max(self.r[index][index].abs()));
+		let tolerance = largest * T::epsilon() * T::from(rows).unwrap();
+		if (0..columns).any(|index| self.r[index][index].abs() <= tolerance) {
+			return Err(MatrixError::Singular);
+		}
+		for row in (0..columns).rev() {
+			for column in row + 1..columns {
+				y[row] = y[row] - self.r[row][column] * y[column];
+			}
+			y[row] = y[row] / self.r[row][row];
+		}
+		let residual_norm = y[columns..]
+			.iter()
+			.fold(T::zero(), |sum, value| sum + *value * *value)
+			.sqrt();
+		y.truncate(columns);
+		Ok(LeastSquares {
+			solution: y,
+			residual_norm,
+		})
+	}
+}
+
+/// Solves the overdetermined system `matrix·x = rhs` in the least squares
+/// sense through a QR factorization of `matrix`.
+pub fn least_squares<T: Float>(
+	matrix: &[Vec<T>],
+	rhs: &[T],
+) -> Result<LeastSquares<T>, MatrixError> {
+	QrDecomposition::new(matrix)?.least_squares(rhs)
+}
+
+//applies I - 2·v·vᵀ to `vector` in place
+pub(super) fn reflect<T: Float>(vector: &mut [T], v: &[T]) {
+	let projection = vector
+		.iter()
+		.zip(v.iter())
+		.fold(T::zero(), |sum, (value, v)| sum + *value * *v);
+	for (value, v) in vector.iter_mut().zip(v.iter()) {
+		*value = *value - (projection + projection) * *v;
+	}
+}
+
+#[cfg(test)]
+mod test_qr {
+	use super::{least_squares, QrDecomposition};
+	use crate::{Fsize, MatrixError, MatrixOperations};
+
+	fn assert_matrix_close(expected: &[Vec<Fsize>], result: &[Vec<Fsize>]) {
+		assert_eq!(expected.len(), result.len());
+		for (expected_row, result_row) in expected.iter().zip(result.iter()) {
+			for (expected, result) in expected_row.iter().zip(result_row.iter()) {
+				assert!((expected - result).abs() < 1e-9);
+			}
+		}
+	}
+
+	#[test]
+	fn test_qr_factors() {
+		let matrix: Vec<Vec<Fsize>> = vec![
+			vec![12.0, -51.0, 4.0],
+			vec![6.0, 167.0, -68.0],
+			vec![-4.0, 24.0, -41.0],
+			vec![1.0, 2.0, 3.0],
+		];
+		let qr = QrDecomposition::new(&matrix).unwrap();
+		let q = qr.q();
+		let r = qr.r();
+
+		assert_matrix_close(&matrix, &q.matrix_mult_matrix(&r));
+		assert_matrix_close(
+			&[
+				vec![1.0, 0.0, 0.0, 0.0],
+				vec![0.0, 1.0, 0.0, 0.0],
+				vec![0.0, 0.0, 1.0, 0.0],
+				vec![0.0, 0.0, 0.0, 1.0],
+			],
+			&q.transpose().matrix_mult_matrix(&q),
+		);
+		for (row, vec_row) in r.iter().enumerate() {
+			assert!(vec_row[..row.min(3)].iter().all(|value| *value == 0.0));
+		}
+	}
+	#[test]
+	fn test_least_squares() {
+		// y = 1 + 2x fitted through points that lie exactly on the line
+		let matrix: Vec<Vec<Fsize>> = vec![
+			vec![1.0, 0.0],
+			vec![1.0, 1.0],
+			vec![1.0, 2.0],
+			vec![1.0, 3.0],
+		];
+		let exact = least_squares(&matrix, &[1.0, 3.0, 5.0, 7.0]).unwrap();
+		assert_matrix_close(&[vec![1.0, 2.0]], &[exact.solution]);
+		assert!(exact.residual_norm.abs() < 1e-9);
+
+		// the best fit through (0, 0), (1, 1), (2, 1) is y = 1/6 + x/2
+		let fit = least_squares(&matrix[..3], &[0.0, 1.0, 1.0]).unwrap();
+		assert_matrix_close(&[vec![1.0 / 6.0, 0.5]], &[fit.solution]);
+		assert!((fit.residual_norm - (1.0 as Fsize / 6.0).sqrt()).abs() < 1e-9);
+	}
+	#[test]
+	fn test_least_squares_errors() {
+		let rank_deficient: Vec<Vec<Fsize>> = vec![vec![1.0, 2.0], vec![2.0, 4.0], vec![3.0, 6.0]];
+		assert_eq!(
+			Err(MatrixError::Singular),
+			least_squares(&rank_deficient, &[1.0, 2.0, 3.0])
+		);
+		assert_eq!(
+			Err(MatrixError::Underdetermined {
+				rows: 1,
+				columns: 2
+			}),
+			least_squares(&[vec![1.0, 2.0]], &[1.0])
+		);
+		assert_eq!(
+			Err(MatrixError::DimensionMismatch {
+				lhs: (3, 2),
+				rhs: (2, 1)
+			}),
+			least_squares(&rank_deficient, &[1.0, 2.0])
+		);
+	}
+}
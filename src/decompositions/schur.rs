@@ -0,0 +1,271 @@
+use super::Hessenberg;
+use crate::MatrixError;
+use num::{Complex, Float};
+
+//iterations allowed per eigenvalue before giving up, convergence usually
+//takes two or three
+const MAX_ITERATIONS: usize = 100;
+
+/// Real Schur decomposition, `A = Q·T·Qᵀ`, computed with the Francis double
+/// shift QR algorithm on the Hessenberg form of `A`.
+///
+/// `T` is upper quasi-triangular: real eigenvalues sit on its diagonal and
+/// each complex conjugate pair forms a 2x2 block on it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RealSchur<T> {
+	t: Vec<Vec<T>>,
+	q: Vec<Vec<T>>,
+	eigenvalues: Vec<Complex<T>>,
+}
+
+impl<T: Float> RealSchur<T> {
+	/// Fails with `NotConverged` if an eigenvalue is not isolated within a
+	/// bounded number of QR steps.
+	pub fn new(matrix: &[Vec<T>]) -> Result<Self, MatrixError> {
+		let (h, q) = Hessenberg::new(matrix)?.into_parts();
+		francis(h, q)
+	}
+
+	/// The upper quasi-triangular factor.
+	pub fn t(&self) -> &[Vec<T>] {
+		&self.t
+	}
+
+	/// The orthogonal factor.
+	pub fn q(&self) -> &[Vec<T>] {
+		&self.q
+	}
+
+	/// The eigenvalues in the order they appear on the diagonal of `T`, with
+	/// the positive imaginary part first in each conjugate pair.
+	pub fn eigenvalues(&self) -> &[Complex<T>] {
+		&self.eigenvalues
+	}
+}
+
+//the double shift QR iteration of JAMA's hqr2, applied to a Hessenberg matrix
+//`h` and accumulating the transformations into `q`
+#[allow(clippy::needless_range_loop)]
+fn francis<T: Float>(mut h: Vec<Vec<T>>, mut q: Vec<Vec<T>>) -> Result<RealSchur<T>, MatrixError> {
+	let size = h.len();
+	let constant = |value: f64| T::from(value).unwrap();
+	let two = constant(2.0);
+	let eps = T::epsilon();
+	let mut real = vec![T::zero(); size];
+	let mut imaginary = vec![T::zero(); size];
+	let mut exshift = T::zero();
+	let mut iterations = 0;
+	let norm = h
+		.iter()
+		.flatten()
+		.fold(T::zero(), |sum, value| sum + value.abs());
+
+	// n is the last row of the active block and l its first
+	let mut n = size as isize - 1;
+	while n >= 0 {
+		let nu = n as usize;
+		let mut l = nu;
+		while l > 0 {
+			let mut s = h[l - 1][l - 1].abs() + h[l][l].abs();
+			if s == T::zero() {
+				s = norm;
+			}
+			if h[l][l - 1].abs() <= eps * s {
+				break;
+			}
+			l -= 1;
+		}
+
+		if l == nu {
+			// a single real root
+			h[nu][nu] = h[nu][nu] + exshift;
+			real[nu] = h[nu][nu];
+			n -= 1;
+			iterations = 0;
+		} else if l + 1 == nu {
+			// a 2x2 block, split into two real roots when possible
+			let m = nu - 1;
+			let w = h[nu][m] * h[m][nu];
+			let p = (h[m][m] - h[nu][nu]) / two;
+			let discriminant = p * p + w;
+			let mut z = discriminant.abs().sqrt();
+			h[nu][nu] = h[nu][nu] + exshift;
+			h[m][m] = h[m][m] + exshift;
+			let x = h[nu][nu];
+			if discriminant >= T::zero() {
+				z = if p >= T::zero() { p + z } else { p - z };
+				real[m] = x + z;
+				real[nu] = if z != T::zero() { x - w / z } else { x + z };
+				// rotate the block to upper triangular
+				let x = h[nu][m];
+				let s = x.abs() + z.abs();
+				let r = ((x / s) * (x / s) + (z / s) * (z / s)).sqrt();
+				let (sin, cos) = (x / s / r, z / s / r);
+				for column in m..size {
+					let top = h[m][column];
+					h[m][column] = cos * top + sin * h[nu][column];
+					h[nu][column] = cos * h[nu][column] - sin * top;
+				}
+				for row in h.iter_mut().take(nu + 1).chain(q.iter_mut()) {
+					let left = row[m];
+					row[m] = cos * left + sin * row[nu];
+					row[nu] = cos * row[nu] - sin * left;
+				}
+				h[nu][m] = T::zero();
+			} else {
+				real[m] = x + p;
+				real[nu] = x + p;
+				imaginary[m] = z;
+				imaginary[nu] = -z;
+			}
+			n -= 2;
+			iterations = 0;
+		} else {
+			if iterations == MAX_ITERATIONS {
+				return Err(MatrixError::NotConverged {
+					iterations: MAX_ITERATIONS,
+				});
+			}
+			// form the shift
+			let mut x = h[nu][nu];
+			let mut y = h[nu - 1][nu - 1];
+			let mut w = h[nu][nu - 1] * h[nu - 1][nu];
+			// Wilkinson's and then MATLAB's exceptional shifts break cycles
+			if iterations == 10 {
+				exshift = exshift + x;
+				for (index, row) in h.iter_mut().enumerate().take(nu + 1) {
+					row[index] = row[index] - x;
+				}
+				let s = h[nu][nu - 1].abs() + h[nu - 1][nu - 2].abs();
+				x = constant(0.75) * s;
+				y = x;
+				w = constant(-0.4375) * s * s;
+			}
+			if iterations == 30 {
+				let half = (y - x) / two;
+				let mut s = half * half + w;
+				if s > T::zero() {
+					s = s.sqrt();
+					if y < x {
+						s = -s;
+					}
+					s = x - w / (half + s);
+					for (index, row) in h.iter_mut().enumerate().take(nu + 1) {
+						row[index] = row[index] - s;
+					}
+					exshift = exshift + s;
+					x = constant(0.964);
+					y = x;
+					w = x;
+				}
+			}
+			iterations += 1;
+
+			// look for two consecutive small subdiagonal elements
+			let mut m = nu - 2;
+			let (mut p, mut q_, mut r);
+			loop {
+				let z = h[m][m];
+				r = x - z;
+				let s = y - z;
+				p = (r * s - w) / h[m + 1][m] + h[m][m + 1];
+				q_ = h[m + 1][m + 1] - z - r - s;
+				r = h[m + 2][m + 1];
+				let s = p.abs() + q_.abs() + r.abs();
+				p = p / s;
+				q_ = q_ / s;
+				r = r / s;
+				if m == l {
+					break;
+				}
+				if h[m][m - 1].abs() * (q_.abs() + r.abs())
+					< eps * (p.abs() * (h[m - 1][m - 1].abs() + z.abs() + h[m + 1][m + 1].abs()))
+				{
+					break;
+				}
+				m -= 1;
+			}
+			for index in m + 2..=nu {
+				h[index][index - 2] = T::zero();
+				if index > m + 2 {
+					h[index][index - 3] = T::zero();
+				}
+			}
+
+			// double QR step on rows l to n and columns m to n
+			for k in m..nu {
+				let not_last = k != nu - 1;
+				if k != m {
+					p = h[k][k - 1];
+					q_ = h[k + 1][k - 1];
+					r = if not_last { h[k + 2][k - 1] } else { T::zero() };
+					x = p.abs() + q_.abs() + r.abs();
+					if x == T::zero() {
+						continue;
+					}
+					p = p / x;
+					q_ = q_ / x;
+					r = r / x;
+				}
+				let mut s = (p * p + q_ * q_ + r * r).sqrt();
+				if p < T::zero() {
+					s = -s;
+				}
+				if s == T::zero() {
+					continue;
+				}
+				if k != m {
+					h[k][k - 1] = -s * x;
+				} else if l != m {
+					h[k][k - 1] = -h[k][k - 1];
+				}
+				p = p + s;
+				let (x, y, z) = (p / s, q_ / s, r / s);
+				q_ = q_ / p;
+				r = r / p;
+
+				for column in k..size {
+					let mut p = h[k][column] + q_ * h[k + 1][column];
+					if not_last {
+						p = p + r * h[k + 2][column];
+						h[k + 2][column] = h[k + 2][column] - p * z;
+					}
+					h[k][column] = h[k][column] - p * x;
+					h[k + 1][column] = h[k + 1][column] - p * y;
+				}
+				let last_row = nu.min(k + 3);
+				for row in h.iter_mut().take(last_row + 1).chain(q.iter_mut()) {
+					let mut p = x * row[k] + y * row[k + 1];
+					if not_last {
+						p = p + z * row[k + 2];
+						row[k + 2] = row[k + 2] - p * r;
+					}
+					row[k] = row[k] - p;
+					row[k + 1] = row[k + 1] - p * q_;
+				}
+			}
+		}
+	}
+
+	// clear the negligible entries left below the quasi-triangle
+	for (row, vec_row) in h.iter_mut().enumerate().skip(1) {
+		// the second row of a conjugate pair keeps its subdiagonal entry
+		let end = if imaginary[row - 1] > T::zero() {
+			row - 1
+		} else {
+			row
+		};
+		vec_row[..end]
+			.iter_mut()
+			.for_each(|value| *value = T::zero());
+	}
+	Ok(RealSchur {
+		t: h,
+		q,
+		eigenvalues: real
+			.into_iter()
+			.zip(imaginary)
+			.map(|(re, im)| Complex::new(re, im))
+			.collect(),
+	})
+}
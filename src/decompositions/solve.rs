@@ -0,0 +1,69 @@
+use super::{
+	solve_lower_triangular, solve_upper_triangular, Cholesky, LuDecomposition, QrDecomposition,
+};
+use crate::{matrix_shape, MatrixError};
+use num::Float;
+
+//solves matrix·x = rhs with the cheapest factorization its structure allows:
+//substitution for triangular matrices, Cholesky for symmetric positive
+//definite ones, LU for any other square one and QR least squares for tall ones
+pub(crate) fn solve_system<T: Float>(matrix: &[Vec<T>], rhs: &[T]) -> Result<Vec<T>, MatrixError> {
+	let (rows, columns) = matrix_shape(matrix)?;
+	if rhs.is_empty() {
+		return Err(MatrixError::EmptyOperand);
+	} else if rhs.len() != rows {
+		return Err(MatrixError::DimensionMismatch {
+			lhs: (rows, columns),
+			rhs: (rhs.len(), 1),
+		});
+	}
+	if rows != columns {
+		return QrDecomposition::new(matrix)?
+			.least_squares(rhs)
+			.map(|fit| fit.solution);
+	}
+
+	// pivots this small relative to the entries only amplify rounding errors,
+	// the same tolerance LuDecomposition applies
+	let largest = matrix
+		.iter()
+		.flatten()
+		.fold(T::zero(), |max, value| max.max(value.abs()));
+	let tolerance = largest * T::epsilon() * T::from(rows).unwrap();
+
+	let is_upper = matrix
+		.iter()
+		.enumerate()
+		.all(|(row, vec_row)| vec_row[..row].iter().all(|value| *value == T::zero()));
+	let is_lower = matrix
+		.iter()
+		.enumerate()
+		.all(|(row, vec_row)| vec_row[row + 1..].iter().all(|value| *value == T::zero()));
+	if is_upper || is_lower {
+		if matrix
+			.iter()
+			.enumerate()
+			.any(|(index, row)| row[index].abs() <= tolerance)
+		{
+			return Err(MatrixError::Singular);
+		}
+		return if is_upper {
+			solve_upper_triangular(matrix, rhs)
+		} else {
+			solve_lower_triangular(matrix, rhs)
+		};
+	}
+	if let Ok(cholesky) = Cholesky::new(matrix) {
+		// L[i][i]² is the pivot LU would have produced, a small one leaves
+		// the decision to LU so both factorizations agree on singularity
+		let has_small_pivot = cholesky
+			.l()
+			.iter()
+			.enumerate()
+			.any(|(index, row)| row[index] * row[index] <= tolerance);
+		if !has_small_pivot {
+			return cholesky.solve(rhs);
+		}
+	}
+	LuDecomposition::new(matrix)?.solve(rhs)
+}
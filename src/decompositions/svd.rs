@@ -0,0 +1,359 @@
+use crate::{matrix_shape, MatrixError};
+use num::Float;
+
+//sweeps over every pair of columns before giving up with NotConverged
+const MAX_SWEEPS: usize = 100;
+
+/// Thin singular value decomposition, `A = U·Σ·Vᵀ`, computed with one sided
+/// Jacobi rotations.
+///
+/// For an `m x n` matrix with `k = min(m, n)`, `U` is `m x k`, `Σ` holds `k`
+/// singular values in descending order and `Vᵀ` is `k x n`. The columns of
+/// `U` and rows of `Vᵀ` are orthonormal even for rank deficient matrices, so
+/// `U·Vᵀ` of a square `A` is always orthogonal, as best-fit rotations need.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Svd<T> {
+	u: Vec<Vec<T>>,
+	singular_values: Vec<T>,
+	v_t: Vec<Vec<T>>,
+}
+
+impl<T: Float> Svd<T> {
+	/// Fails with `NotConverged` if the columns are still not orthogonal
+	/// after a bounded number of sweeps.
+	pub fn new(matrix: &[Vec<T>]) -> Result<Self, MatrixError> {
+		let (rows, columns) = matrix_shape(matrix)?;
+		if rows >= columns {
+			let (u, singular_values, v) = jacobi(columns_of(matrix, rows, columns))?;
+			Ok(Svd {
+				u: rows_of(&u),
+				singular_values,
+				v_t: v,
+			})
+		} else {
+			// Aᵀ = U'·Σ·V'ᵀ gives A = V'·Σ·U'ᵀ
+			let transposed: Vec<Vec<T>> = (0..columns)
+				.map(|column| matrix.iter().map(|row| row[column]).collect())
+				.collect();
+			let (u, singular_values, v) = jacobi(columns_of(&transposed, columns, rows))?;
+			Ok(Svd {
+				u: rows_of(&v),
+				singular_values,
+				v_t: u,
+			})
+		}
+	}
+
+	pub fn u(&self) -> &[Vec<T>] {
+		&self.u
+	}
+
+	/// The singular values, largest first.
+	pub fn singular_values(&self) -> &[T] {
+		&self.singular_values
+	}
+
+	pub fn v_t(&self) -> &[Vec<T>] {
+		&self.v_t
+	}
+
+	/// The number of singular values greater than `tolerance`.
+	pub fn rank(&self, tolerance: T) -> usize {
+		self.singular_values
+			.iter()
+			.filter(|value| **value > tolerance)
+			.count()
+	}
+
+	/// The ratio of the largest to the smallest singular value, infinite for
+	/// singular matrices.
+	pub fn condition_number(&self) -> T {
+		let smallest = self.singular_values[self.singular_values.len() - 1];
+		if smallest == T::zero() {
+			return T::infinity();
+		}
+		self.singular_values[0] / smallest
+	}
+
+	/// The Moore-Penrose pseudo-inverse, `V·Σ⁺·Uᵀ`, treating singular values
+	/// no greater than `tolerance` as zero.
+	pub fn pseudo_inverse(&self, tolerance: T) -> Vec<Vec<T>> {
+		let rows = self.u.len();
+		let columns = self.v_t[0].len();
+		let mut result = vec![vec![T::zero(); rows]; columns];
+		for (index, sigma) in self.singular_values.iter().enumerate() {
+			if *sigma <= tolerance {
+				continue;
+			}
+			for (column, result_row) in result.iter_mut().enumerate() {
+				let scaled = self.v_t[index][column] / *sigma;
+				for (value, u_row) in result_row.iter_mut().zip(self.u.iter()) {
+					*value = *value + scaled * u_row[index];
+				}
+			}
+		}
+		result
+	}
+}
+
+fn columns_of<T: Float>(matrix: &[Vec<T>], rows: usize, columns: usize) -> Vec<Vec<T>> {
+	(0..columns)
+		.map(|column| (0..rows).map(|row| matrix[row][column]).collect())
+		.collect()
+}
+
+fn rows_of<T: Float>(columns: &[Vec<T>]) -> Vec<Vec<T>> {
+	(0..columns[0].len())
+		.map(|row| columns.iter().map(|column| column[row]).collect())
+		.collect()
+}
+
+fn dot<T: Float>(lhs: &[T], rhs: &[T]) -> T {
+	lhs.iter()
+		.zip(rhs.iter())
+		.fold(T::zero(), |sum, (lhs, rhs)| sum + *lhs * *rhs)
+}
+
+//rotates columns p and q by the angle whose cosine is c and sine is s
+fn rotate<T: Float>(columns: &mut [Vec<T>], p: usize, q: usize, c: T, s: T) {
+	let (head, tail) = columns.split_at_mut(q);
+	for (x, y) in head[p].iter_mut().zip(tail[0].iter_mut()) {
+		let (old_x, old_y) = (*x, *y);
+		*x = c * old_x - s * old_y;
+		*y = s * old_x + c * old_y;
+	}
+}
+
+//U as columns, the singular values and Vᵀ
+type Factors<T> = (Vec<Vec<T>>, Vec<T>, Vec<Vec<T>>);
+
+//orthogonalizes the columns of a tall matrix, returning the factors with the
+//singular values sorted
+fn jacobi<T: Float>(mut u: Vec<Vec<T>>) -> Result<Factors<T>, MatrixError> {
+	let size = u.len();
+	let mut v: Vec<Vec<T>> = (0..size)
+		.map(|row| {
+			(0..size)
+				.map(|column| if row == column { T::one() } else { T::zero() })
+				.collect()
+		})
+		.collect();
+
+	let mut converged = false;
+	for _ in 0..MAX_SWEEPS {
+		let mut rotated = false;
+		for p in 0..size {
+			for q in p + 1..size {
+				let alpha = dot(&u[p], &u[p]);
+				let beta = dot(&u[q], &u[q]);
+				let gamma = dot(&u[p], &u[q]);
+				if gamma.abs() <= T::epsilon() * (alpha * beta).sqrt() {
+					continue;
+				}
+				rotated = true;
+				let zeta = (beta - alpha) / (gamma + gamma);
+				let t = zeta.signum() / (zeta.abs() + (T::one() + zeta * zeta).sqrt());
+				let c = T::one() / (T::one() + t * t).sqrt();
+				let s = c * t;
+				rotate(&mut u, p, q, c, s);
+				rotate(&mut v, p, q, c, s);
+			}
+		}
+		if !rotated {
+			converged = true;
+			break;
+		}
+	}
+	if !converged {
+		return Err(MatrixError::NotConverged {
+			iterations: MAX_SWEEPS,
+		});
+	}
+
+	let mut order: Vec<(T, usize)> = u
+		.iter()
+		.enumerate()
+		.map(|(index, column)| (dot(column, column).sqrt(), index))
+		.collect();
+	order.sort_by(|lhs, rhs| {
+		rhs.0
+			.partial_cmp(&lhs.0)
+			.unwrap_or(std::cmp::Ordering::Equal)
+	});
+
+	// columns this small relative to the largest are rounding noise, their
+	// direction in U is replaced by one orthogonal to the rest
+	let largest = order.first().map_or(T::zero(), |(sigma, _)| *sigma);
+	let length = u.first().map_or(0, |column| column.len());
+	let null_tolerance = largest * T::epsilon() * T::from(length).unwrap();
+	let singular_values = order.iter().map(|(sigma, _)| *sigma).collect();
+	let mut u_columns: Vec<Vec<T>> = order
+		.iter()
+		.filter(|(sigma, _)| *sigma > null_tolerance)
+		.map(|(sigma, index)| u[*index].iter().map(|value| *value / *sigma).collect())
+		.collect();
+	while u_columns.len() < size {
+		let column = complement(&u_columns, length);
+		u_columns.push(column);
+	}
+	// the columns of V are the rows of Vᵀ
+	let v_t = order.iter().map(|(_, index)| v[*index].clone()).collect();
+	Ok((u_columns, singular_values, v_t))
+}
+
+//a unit vector orthogonal to the orthonormal `basis`, taken from the standard
+//basis vector with the largest component outside its span, which is at
+//least 1/sqrt(length) while the basis has fewer than `length` vectors
+fn complement<T: Float>(basis: &[Vec<T>], length: usize) -> Vec<T> {
+	let mut best: Vec<T> = Vec::new();
+	let mut best_norm = -T::one();
+	for axis in 0..length {
+		let mut candidate = vec![T::zero(); length];
+		candidate[axis] = T::one();
+		for vector in basis.iter() {
+			let projection = vector[axis];
+			for (value, basis_value) in candidate.iter_mut().zip(vector.iter()) {
+				*value = *value - projection * *basis_value;
+			}
+		}
+		let norm = dot(&candidate, &candidate).sqrt();
+		if norm > best_norm {
+			best = candidate;
+			best_norm = norm;
+		}
+	}
+	best.into_iter().map(|value| value / best_norm).collect()
+}
+
+#[cfg(test)]
+mod test_svd {
+	use super::{Svd, MAX_SWEEPS};
+	use crate::{Fsize, MatrixError, MatrixInverse, MatrixOperations};
+
+	fn assert_matrix_close(expected: &[Vec<Fsize>], result: &[Vec<Fsize>]) {
+		assert_eq!(expected.len(), result.len());
+		for (expected_row, result_row) in expected.iter().zip(result.iter()) {
+			assert_eq!(expected_row.len(), result_row.len());
+			for (expected, result) in expected_row.iter().zip(result_row.iter()) {
+				assert!((expected - result).abs() < 1e-9);
+			}
+		}
+	}
+	fn reconstruct(svd: &Svd<Fsize>) -> Vec<Vec<Fsize>> {
+		let u_sigma: Vec<Vec<Fsize>> = svd
+			.u()
+			.iter()
+			.map(|row| {
+				row.iter()
+					.zip(svd.singular_values().iter())
+					.map(|(value, sigma)| value * sigma)
+					.collect()
+			})
+			.collect();
+		u_sigma.matrix_mult_matrix(&svd.v_t().to_vec())
+	}
+
+	#[test]
+	fn test_svd_reconstruction() {
+		let tall: Vec<Vec<Fsize>> = vec![
+			vec![2.0, 0.0, 1.0],
+			vec![-1.0, 3.0, 0.5],
+			vec![0.0, 1.0, 4.0],
+			vec![1.0, 1.0, 1.0],
+		];
+		let wide = tall.transpose();
+		for matrix in [tall, wide].iter() {
+			let svd = Svd::new(matrix).unwrap();
+			assert_matrix_close(matrix, &reconstruct(&svd));
+			let values = svd.singular_values();
+			assert_eq!(3, values.len());
+			assert!(values[0] >= values[1] && values[1] >= values[2]);
+		}
+	}
+	fn assert_orthonormal_columns(matrix: &[Vec<Fsize>]) {
+		let columns = matrix[0].len();
+		let identity: Vec<Vec<Fsize>> = (0..columns)
+			.map(|row| {
+				(0..columns)
+					.map(|column| if row == column { 1.0 } else { 0.0 })
+					.collect()
+			})
+			.collect();
+		assert_matrix_close(
+			&identity,
+			&matrix
+				.to_vec()
+				.transpose()
+				.matrix_mult_matrix(&matrix.to_vec()),
+		);
+	}
+
+	#[test]
+	fn test_svd_rank_deficient() {
+		let planar: Vec<Vec<Fsize>> = vec![
+			vec![1.0, 0.0, 0.0],
+			vec![0.0, 1.0, 0.0],
+			vec![0.0, 0.0, 0.0],
+		];
+		let rank_one: Vec<Vec<Fsize>> = vec![vec![1.0, 2.0], vec![2.0, 4.0], vec![3.0, 6.0]];
+		let zero: Vec<Vec<Fsize>> = vec![vec![0.0; 2]; 2];
+		for matrix in [planar, rank_one.clone(), zero].iter() {
+			let svd = Svd::new(matrix).unwrap();
+			assert_orthonormal_columns(svd.u());
+			assert_orthonormal_columns(&svd.v_t().to_vec().transpose());
+			assert_matrix_close(matrix, &reconstruct(&svd));
+		}
+
+		// wide matrices complete Vᵀ instead
+		let wide = rank_one.transpose();
+		let svd = Svd::new(&wide).unwrap();
+		assert_orthonormal_columns(&svd.v_t().to_vec().transpose());
+		assert_matrix_close(&wide, &reconstruct(&svd));
+	}
+	#[test]
+	fn test_svd_rank_and_condition() {
+		let diagonal: Vec<Vec<Fsize>> = vec![
+			vec![1.0, 0.0, 0.0],
+			vec![0.0, -4.0, 0.0],
+			vec![0.0, 0.0, 2.0],
+		];
+		let svd = Svd::new(&diagonal).unwrap();
+		assert_matrix_close(&[vec![4.0, 2.0, 1.0]], &[svd.singular_values().to_vec()]);
+		assert!((svd.condition_number() - 4.0).abs() < 1e-9);
+		assert_eq!(3, svd.rank(1e-9));
+
+		let rank_one: Vec<Vec<Fsize>> = vec![vec![1.0, 2.0], vec![2.0, 4.0], vec![3.0, 6.0]];
+		let svd = Svd::new(&rank_one).unwrap();
+		assert_eq!(1, svd.rank(1e-9));
+		assert!(svd.condition_number() > 1e9);
+	}
+	#[test]
+	fn test_pseudo_inverse() {
+		let invertible: Vec<Vec<Fsize>> = vec![vec![4.0, 7.0], vec![2.0, 6.0]];
+		assert_matrix_close(
+			&invertible.matrix_inverse(1e-12),
+			&Svd::new(&invertible).unwrap().pseudo_inverse(1e-9),
+		);
+
+		// A·A⁺·A = A holds even without full rank
+		let rank_one: Vec<Vec<Fsize>> = vec![vec![1.0, 2.0, 3.0], vec![2.0, 4.0, 6.0]];
+		let pseudo_inverse = Svd::new(&rank_one).unwrap().pseudo_inverse(1e-9);
+		assert_eq!(3, pseudo_inverse.len());
+		assert_matrix_close(
+			&rank_one,
+			&rank_one
+				.matrix_mult_matrix(&pseudo_inverse)
+				.matrix_mult_matrix(&rank_one),
+		);
+	}
+	#[test]
+	fn test_svd_not_converged() {
+		let matrix: Vec<Vec<Fsize>> = vec![vec![1.0, Fsize::NAN], vec![2.0, 3.0]];
+		assert_eq!(
+			Err(MatrixError::NotConverged {
+				iterations: MAX_SWEEPS
+			}),
+			Svd::new(&matrix)
+		);
+	}
+}
@@ -0,0 +1,198 @@
+use crate::{symmetric_size, Fsize, Matrix3D, MatrixError};
+use num::Float;
+
+//full sweeps over the off diagonal entries before giving up with NotConverged
+const MAX_SWEEPS: usize = 100;
+
+/// Eigendecomposition of a real symmetric matrix, `A = V·Λ·Vᵀ`, computed
+/// with cyclic Jacobi rotations.
+///
+/// Eigenvalues are sorted in ascending order and `eigenvectors()[i]` is the
+/// unit eigenvector paired with `eigenvalues()[i]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SymmetricEigen<T> {
+	eigenvalues: Vec<T>,
+	eigenvectors: Vec<Vec<T>>,
+}
+
+impl<T: Float> SymmetricEigen<T> {
+	/// Fails with `NotSymmetric` when mirrored entries differ by more than a
+	/// relative tolerance of `sqrt(T::epsilon())`, and with `NotConverged` if
+	/// the off diagonal entries do not vanish within a bounded number of
+	/// sweeps.
+	pub fn new(matrix: &[Vec<T>]) -> Result<Self, MatrixError> {
+		symmetric_size(matrix)?;
+		jacobi(matrix.to_vec())
+	}
+
+	pub fn eigenvalues(&self) -> &[T] {
+		&self.eigenvalues
+	}
+
+	pub fn eigenvectors(&self) -> &[Vec<T>] {
+		&self.eigenvectors
+	}
+}
+
+impl SymmetricEigen<Fsize> {
+	/// Decomposes the upper left 3x3 block of `matrix`, such as an inertia
+	/// tensor or the linear part of a symmetric transform.
+	pub fn from_matrix3d(matrix: &Matrix3D) -> Result<Self, MatrixError> {
+		let block: Vec<Vec<Fsize>> = matrix[..3].iter().map(|row| row[..3].to_vec()).collect();
+		SymmetricEigen::new(&block)
+	}
+}
+
+#[allow(clippy::needless_range_loop)]
+fn jacobi<T: Float>(mut a: Vec<Vec<T>>) -> Result<SymmetricEigen<T>, MatrixError> {
+	let size = a.len();
+	// columns of v are the eigenvectors
+	let mut v: Vec<Vec<T>> = (0..size)
+		.map(|row| {
+			(0..size)
+				.map(|column| if row == column { T::one() } else { T::zero() })
+				.collect()
+		})
+		.collect();
+	let norm = a
+		.iter()
+		.flatten()
+		.fold(T::zero(), |sum, value| sum + *value * *value)
+		.sqrt();
+	let threshold = T::epsilon() * norm;
+
+	let mut converged = false;
+	for _ in 0..MAX_SWEEPS {
+		let mut rotated = false;
+		for p in 0..size {
+			for q in p + 1..size {
+				if a[p][q].abs() <= threshold {
+					continue;
+				}
+				rotated = true;
+				let theta = (a[q][q] - a[p][p]) / (a[p][q] + a[p][q]);
+				let t = theta.signum() / (theta.abs() + (theta * theta + T::one()).sqrt());
+				let c = T::one() / (t * t + T::one()).sqrt();
+				let s = c * t;
+				// A = Jᵀ·A·J, applied to the columns then the rows
+				for k in 0..size {
+					let (kp, kq) = (a[k][p], a[k][q]);
+					a[k][p] = c * kp - s * kq;
+					a[k][q] = s * kp + c * kq;
+				}
+				for k in 0..size {
+					let (pk, qk) = (a[p][k], a[q][k]);
+					a[p][k] = c * pk - s * qk;
+					a[q][k] = s * pk + c * qk;
+				}
+				for row in v.iter_mut() {
+					let (kp, kq) = (row[p], row[q]);
+					row[p] = c * kp - s * kq;
+					row[q] = s * kp + c * kq;
+				}
+			}
+		}
+		if !rotated {
+			converged = true;
+			break;
+		}
+	}
+	if !converged {
+		return Err(MatrixError::NotConverged {
+			iterations: MAX_SWEEPS,
+		});
+	}
+
+	let mut order: Vec<usize> = (0..size).collect();
+	order.sort_by(|lhs, rhs| {
+		a[*lhs][*lhs]
+			.partial_cmp(&a[*rhs][*rhs])
+			.unwrap_or(std::cmp::Ordering::Equal)
+	});
+	Ok(SymmetricEigen {
+		eigenvalues: order.iter().map(|index| a[*index][*index]).collect(),
+		eigenvectors: order
+			.iter()
+			.map(|index| v.iter().map(|row| row[*index]).collect())
+			.collect(),
+	})
+}
+
+#[cfg(test)]
+mod test_symmetric_eigen {
+	use super::{SymmetricEigen, MAX_SWEEPS};
+	use crate::{Fsize, MatrixError, MatrixOperations, VectorOps};
+
+	fn assert_close(expected: Fsize, result: Fsize) {
+		assert!((expected - result).abs() < 1e-9);
+	}
+	fn assert_decomposition(matrix: &Vec<Vec<Fsize>>, eigen: &SymmetricEigen<Fsize>) {
+		let vectors = eigen.eigenvectors();
+		for (value, vector) in eigen.eigenvalues().iter().zip(vectors.iter()) {
+			let product = matrix.matrix_mult_point(vector);
+			for (lhs, rhs) in product.iter().zip(vector.vec_scal(*value).iter()) {
+				assert_close(*rhs, *lhs);
+			}
+		}
+		for (index, lhs) in vectors.iter().enumerate() {
+			for (other, rhs) in vectors.iter().enumerate() {
+				assert_close(if index == other { 1.0 } else { 0.0 }, lhs.dot(rhs));
+			}
+		}
+	}
+
+	#[test]
+	fn test_symmetric_eigen() {
+		let matrix: Vec<Vec<Fsize>> = vec![
+			vec![4.0, 1.0, -2.0, 2.0],
+			vec![1.0, 2.0, 0.0, 1.0],
+			vec![-2.0, 0.0, 3.0, -2.0],
+			vec![2.0, 1.0, -2.0, -1.0],
+		];
+		let eigen = SymmetricEigen::new(&matrix).unwrap();
+		let values = eigen.eigenvalues();
+
+		assert_decomposition(&matrix, &eigen);
+		assert!(values.windows(2).all(|pair| pair[0] <= pair[1]));
+		assert_close(matrix.trace(), values.iter().sum());
+		assert_close(matrix.determinant(), values.iter().product());
+	}
+	#[test]
+	fn test_symmetric_eigen_matrix3d() {
+		// an inertia tensor with a Y-Z product of inertia, plus a translation
+		// that from_matrix3d ignores
+		let tensor = [
+			[2.0, 0.0, 0.0, 5.0],
+			[0.0, 3.0, 1.0, 5.0],
+			[0.0, 1.0, 3.0, 5.0],
+			[0.0, 0.0, 0.0, 1.0],
+		];
+		let eigen = SymmetricEigen::from_matrix3d(&tensor).unwrap();
+		let block: Vec<Vec<Fsize>> = tensor[..3].iter().map(|row| row[..3].to_vec()).collect();
+
+		assert_decomposition(&block, &eigen);
+		for (expected, value) in [2.0, 2.0, 4.0].iter().zip(eigen.eigenvalues().iter()) {
+			assert_close(*expected, *value);
+		}
+	}
+	#[test]
+	fn test_symmetric_eigen_errors() {
+		assert_eq!(
+			Err(MatrixError::NotSymmetric { row: 0, column: 1 }),
+			SymmetricEigen::new(&[vec![1.0, 2.0], vec![3.0, 1.0]])
+		);
+		assert_eq!(
+			Err(MatrixError::NotSquare {
+				rows: 1,
+				columns: 2
+			}),
+			SymmetricEigen::new(&[vec![1.0, 2.0]])
+		);
+		assert_eq!(
+			Err(MatrixError::NotConverged {
+				iterations: MAX_SWEEPS
+			}),
+			SymmetricEigen::new(&[vec![Fsize::NAN, 1.0], vec![1.0, 2.0]])
+		);
+	}
+}
@@ -0,0 +1,96 @@
+use crate::{square_size, MatrixError};
+use num::Float;
+
+/// Solves `matrix·x = rhs` by forward substitution, reading only the lower
+/// triangle of `matrix`.
+pub fn solve_lower_triangular<T: Float>(
+	matrix: &[Vec<T>],
+	rhs: &[T],
+) -> Result<Vec<T>, MatrixError> {
+	check_system(matrix, rhs)?;
+	let mut x = rhs.to_vec();
+	for (row, vec_row) in matrix.iter().enumerate() {
+		let sum = vec_row[..row]
+			.iter()
+			.zip(x[..row].iter())
+			.fold(x[row], |sum, (a, x)| sum - *a * *x);
+		x[row] = sum / vec_row[row];
+	}
+	Ok(x)
+}
+
+/// Solves `matrix·x = rhs` by back substitution, reading only the upper
+/// triangle of `matrix`.
+pub fn solve_upper_triangular<T: Float>(
+	matrix: &[Vec<T>],
+	rhs: &[T],
+) -> Result<Vec<T>, MatrixError> {
+	check_system(matrix, rhs)?;
+	let mut x = rhs.to_vec();
+	for (row, vec_row) in matrix.iter().enumerate().rev() {
+		let sum = vec_row[row + 1..]
+			.iter()
+			.zip(x[row + 1..].iter())
+			.fold(x[row], |sum, (a, x)| sum - *a * *x);
+		x[row] = sum / vec_row[row];
+	}
+	Ok(x)
+}
+
+//a triangular system is solvable when it is square, matches the right hand
+//side and has no zero on its diagonal
+fn check_system<T: Float>(matrix: &[Vec<T>], rhs: &[T]) -> Result<(), MatrixError> {
+	let size = square_size(matrix)?;
+	if rhs.is_empty() {
+		return Err(MatrixError::EmptyOperand);
+	} else if rhs.len() != size {
+		return Err(MatrixError::DimensionMismatch {
+			lhs: (size, size),
+			rhs: (rhs.len(), 1),
+		});
+	}
+	if matrix
+		.iter()
+		.enumerate()
+		.any(|(index, row)| row[index] == T::zero())
+	{
+		return Err(MatrixError::Singular);
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod test_triangular {
+	use super::{solve_lower_triangular, solve_upper_triangular};
+	use crate::{Fsize, MatrixError};
+
+	#[test]
+	fn test_triangular_solve() {
+		// the entries outside the triangle are never read
+		let matrix: Vec<Vec<Fsize>> = vec![
+			vec![2.0, 1.0, -1.0],
+			vec![4.0, -1.0, 3.0],
+			vec![-2.0, 5.0, 4.0],
+		];
+
+		assert_eq!(
+			Ok(vec![1.0, 2.0, 3.0]),
+			solve_lower_triangular(&matrix, &[2.0, 2.0, 20.0])
+		);
+		assert_eq!(
+			Ok(vec![1.0, 2.0, 3.0]),
+			solve_upper_triangular(&matrix, &[1.0, 7.0, 12.0])
+		);
+		assert_eq!(
+			Err(MatrixError::Singular),
+			solve_upper_triangular(&[vec![1.0, 2.0], vec![0.0, 0.0]], &[1.0, 1.0])
+		);
+		assert_eq!(
+			Err(MatrixError::DimensionMismatch {
+				lhs: (3, 3),
+				rhs: (2, 1)
+			}),
+			solve_lower_triangular(&matrix, &[1.0, 1.0])
+		);
+	}
+}
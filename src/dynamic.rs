@@ -0,0 +1,323 @@
+use crate::{Fsize, MatrixOperations, VectorMD, VectorOps, VectorSD};
+use std::ops::{Add, Index, IndexMut, Mul, Neg, Sub};
+
+/// An owned, heap allocated matrix with arithmetic operators.
+///
+/// The operators delegate to `MatrixOperations` and `VectorOps` on the
+/// wrapped `VectorMD`, so like the non `try_` trait methods they panic on
+/// mismatched shapes. Call the `try_` methods on `.0` to handle the error.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DynMatrix(pub VectorMD);
+
+/// A matrix borrowed from a `VectorMD`, with the same operators as
+/// `DynMatrix`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DynMatrixRef<'a>(pub &'a VectorMD);
+
+/// An owned, heap allocated column vector with arithmetic operators.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DynVector(pub VectorSD);
+
+/// A column vector borrowed from a `VectorSD`, with the same operators as
+/// `DynVector`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DynVectorRef<'a>(pub &'a VectorSD);
+
+impl DynMatrix {
+	pub fn new(data: VectorMD) -> Self {
+		DynMatrix(data)
+	}
+	/// Borrows the matrix, so it can be used in expressions without moving
+	/// or cloning it.
+	pub fn by_ref(&self) -> DynMatrixRef<'_> {
+		DynMatrixRef(&self.0)
+	}
+	fn inner(&self) -> &VectorMD {
+		&self.0
+	}
+}
+
+impl<'a> DynMatrixRef<'a> {
+	pub fn new(data: &'a VectorMD) -> Self {
+		DynMatrixRef(data)
+	}
+	fn inner(&self) -> &VectorMD {
+		self.0
+	}
+}
+
+impl DynVector {
+	pub fn new(data: VectorSD) -> Self {
+		DynVector(data)
+	}
+	/// Borrows the vector, so it can be used in expressions without moving
+	/// or cloning it.
+	pub fn by_ref(&self) -> DynVectorRef<'_> {
+		DynVectorRef(&self.0)
+	}
+	fn inner(&self) -> &VectorSD {
+		&self.0
+	}
+}
+
+impl<'a> DynVectorRef<'a> {
+	pub fn new(data: &'a VectorSD) -> Self {
+		DynVectorRef(data)
+	}
+	fn inner(&self) -> &VectorSD {
+		self.0
+	}
+}
+
+impl From<VectorMD> for DynMatrix {
+	fn from(data: VectorMD) -> Self {
+		DynMatrix(data)
+	}
+}
+
+impl From<DynMatrix> for VectorMD {
+	fn from(matrix: DynMatrix) -> Self {
+		matrix.0
+	}
+}
+
+impl From<DynMatrixRef<'_>> for DynMatrix {
+	fn from(matrix: DynMatrixRef<'_>) -> Self {
+		DynMatrix(matrix.0.clone())
+	}
+}
+
+impl<'a> From<&'a VectorMD> for DynMatrixRef<'a> {
+	fn from(data: &'a VectorMD) -> Self {
+		DynMatrixRef(data)
+	}
+}
+
+impl From<VectorSD> for DynVector {
+	fn from(data: VectorSD) -> Self {
+		DynVector(data)
+	}
+}
+
+impl From<DynVector> for VectorSD {
+	fn from(vector: DynVector) -> Self {
+		vector.0
+	}
+}
+
+impl From<DynVectorRef<'_>> for DynVector {
+	fn from(vector: DynVectorRef<'_>) -> Self {
+		DynVector(vector.0.clone())
+	}
+}
+
+impl<'a> From<&'a VectorSD> for DynVectorRef<'a> {
+	fn from(data: &'a VectorSD) -> Self {
+		DynVectorRef(data)
+	}
+}
+
+//implements $op for every pairing of the owned, referenced and borrowed
+//forms of the two operands
+macro_rules! binary_operator {
+	($op:ident, $method:ident, [$($lhs:ty),*], $rhs:tt, $output:ident, $body:expr) => {
+		$(binary_operator!(@rhs $op, $method, $lhs, $rhs, $output, $body);)*
+	};
+	(@rhs $op:ident, $method:ident, $lhs:ty, [$($rhs:ty),*], $output:ident, $body:expr) => {
+		$(
+			impl $op<$rhs> for $lhs {
+				type Output = $output;
+
+				fn $method(self, rhs: $rhs) -> $output {
+					$output($body(self.inner(), rhs.inner()))
+				}
+			}
+		)*
+	};
+}
+
+macro_rules! scalar_operators {
+	([$($operand:ty),*], $output:ident, $scale:ident) => {
+		$(
+			impl Mul<Fsize> for $operand {
+				type Output = $output;
+
+				fn mul(self, rhs: Fsize) -> $output {
+					$output(self.inner().$scale(rhs))
+				}
+			}
+
+			impl Mul<$operand> for Fsize {
+				type Output = $output;
+
+				fn mul(self, rhs: $operand) -> $output {
+					$output(rhs.inner().$scale(self))
+				}
+			}
+
+			impl Neg for $operand {
+				type Output = $output;
+
+				fn neg(self) -> $output {
+					$output(self.inner().$scale(-1.0))
+				}
+			}
+		)*
+	};
+}
+
+binary_operator!(
+	Add,
+	add,
+	[DynMatrix, &DynMatrix, DynMatrixRef<'_>],
+	[DynMatrix, &DynMatrix, DynMatrixRef<'_>],
+	DynMatrix,
+	|lhs: &VectorMD, rhs: &VectorMD| lhs.matrix_add_matrix(rhs)
+);
+binary_operator!(
+	Sub,
+	sub,
+	[DynMatrix, &DynMatrix, DynMatrixRef<'_>],
+	[DynMatrix, &DynMatrix, DynMatrixRef<'_>],
+	DynMatrix,
+	|lhs: &VectorMD, rhs: &VectorMD| lhs.matrix_add_matrix(&rhs.scaler_mult_matrix(-1.0))
+);
+binary_operator!(
+	Mul,
+	mul,
+	[DynMatrix, &DynMatrix, DynMatrixRef<'_>],
+	[DynMatrix, &DynMatrix, DynMatrixRef<'_>],
+	DynMatrix,
+	|lhs: &VectorMD, rhs: &VectorMD| lhs.matrix_mult_matrix(rhs)
+);
+binary_operator!(
+	Mul,
+	mul,
+	[DynMatrix, &DynMatrix, DynMatrixRef<'_>],
+	[DynVector, &DynVector, DynVectorRef<'_>],
+	DynVector,
+	|lhs: &VectorMD, rhs: &VectorSD| lhs.matrix_mult_point(rhs)
+);
+binary_operator!(
+	Add,
+	add,
+	[DynVector, &DynVector, DynVectorRef<'_>],
+	[DynVector, &DynVector, DynVectorRef<'_>],
+	DynVector,
+	|lhs: &VectorSD, rhs: &VectorSD| lhs.vec_add(rhs)
+);
+binary_operator!(
+	Sub,
+	sub,
+	[DynVector, &DynVector, DynVectorRef<'_>],
+	[DynVector, &DynVector, DynVectorRef<'_>],
+	DynVector,
+	|lhs: &VectorSD, rhs: &VectorSD| lhs.vec_add(&rhs.vec_scal(-1.0))
+);
+scalar_operators!(
+	[DynMatrix, &DynMatrix, DynMatrixRef<'_>],
+	DynMatrix,
+	scaler_mult_matrix
+);
+scalar_operators!([DynVector, &DynVector, DynVectorRef<'_>], DynVector, vec_scal);
+
+impl Index<(usize, usize)> for DynMatrix {
+	type Output = Fsize;
+
+	fn index(&self, (row, column): (usize, usize)) -> &Fsize {
+		&self.0[row][column]
+	}
+}
+
+impl IndexMut<(usize, usize)> for DynMatrix {
+	fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut Fsize {
+		&mut self.0[row][column]
+	}
+}
+
+impl Index<(usize, usize)> for DynMatrixRef<'_> {
+	type Output = Fsize;
+
+	fn index(&self, (row, column): (usize, usize)) -> &Fsize {
+		&self.0[row][column]
+	}
+}
+
+impl Index<usize> for DynVector {
+	type Output = Fsize;
+
+	fn index(&self, index: usize) -> &Fsize {
+		&self.0[index]
+	}
+}
+
+impl IndexMut<usize> for DynVector {
+	fn index_mut(&mut self, index: usize) -> &mut Fsize {
+		&mut self.0[index]
+	}
+}
+
+impl Index<usize> for DynVectorRef<'_> {
+	type Output = Fsize;
+
+	fn index(&self, index: usize) -> &Fsize {
+		&self.0[index]
+	}
+}
+
+#[cfg(test)]
+mod test_dynamic {
+	use crate::{DynMatrix, DynMatrixRef, DynVector, DynVectorRef, MatrixOperations, VectorMD};
+
+	#[test]
+	fn test_matrix_operators() {
+		let data: VectorMD = vec![vec![1.0, 2.0], vec![3.0, 4.0]];
+		let a = DynMatrix::new(data.clone());
+		let b = DynMatrix::new(vec![vec![0.0, 1.0], vec![1.0, 0.0]]);
+		let borrowed = DynMatrixRef::new(&data);
+
+		assert_eq!(
+			DynMatrix(data.matrix_mult_matrix(&b.0).matrix_add_matrix(&data)),
+			&a * &b + borrowed
+		);
+		assert_eq!(DynMatrix(vec![vec![1.0, 1.0], vec![2.0, 4.0]]), &a - &b);
+		assert_eq!(-&a, borrowed * -1.0);
+		assert_eq!(2.0 * &a, a.by_ref() + a.by_ref());
+		assert_eq!(
+			DynVector(vec![5.0, 11.0]),
+			borrowed * DynVector(vec![1.0, 2.0])
+		);
+		assert_eq!(3.0, borrowed[(1, 0)]);
+
+		let mut c = a.clone() * b;
+		c[(0, 1)] = 7.0;
+		assert_eq!(DynMatrix(vec![vec![2.0, 7.0], vec![4.0, 3.0]]), c);
+		assert_eq!(data, Vec::from(a));
+	}
+	#[test]
+	fn test_vector_operators() {
+		let data = vec![1.0, 2.0, 3.0];
+		let u = DynVector::from(data.clone());
+		let v = DynVectorRef::from(&data);
+
+		assert_eq!(DynVector(vec![2.0, 4.0, 6.0]), &u + v);
+		assert_eq!(DynVector(vec![0.0; 3]), u.by_ref() - v);
+		assert_eq!(-&u, -1.0 * v);
+		assert_eq!(u, DynVector::from(v));
+
+		let mut w = u * 2.0;
+		w[2] = 0.0;
+		assert_eq!(DynVector(vec![2.0, 4.0, 0.0]), w);
+		assert_eq!(2.0, v[1]);
+	}
+	#[test]
+	fn test_operator_panics() {
+		let a = DynMatrix(vec![vec![1.0, 2.0]]);
+		let b = DynMatrix(vec![vec![1.0], vec![2.0]]);
+
+		assert!(std::panic::catch_unwind(|| &a + &b).is_err());
+		assert!(std::panic::catch_unwind(|| &a * &a).is_err());
+		assert!(std::panic::catch_unwind(|| &a * DynVector(vec![1.0])).is_err());
+		assert_eq!(DynMatrix(vec![vec![5.0]]), a * b);
+	}
+}
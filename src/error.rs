@@ -0,0 +1,100 @@
+use std::fmt;
+
+/// Errors returned by the fallible `try_` operations.
+///
+/// Shapes are reported as `(rows, columns)`; single dimensional vectors are
+/// treated as column vectors, so a vector of length `n` has shape `(n, 1)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MatrixError {
+	/// The operands do not have compatible shapes.
+	DimensionMismatch {
+		lhs: (usize, usize),
+		rhs: (usize, usize),
+	},
+	/// An operand has no elements.
+	EmptyOperand,
+	/// A row of a matrix does not have the same length as the first row.
+	RaggedRows {
+		row: usize,
+		expected: usize,
+		found: usize,
+	},
+	/// The input is not in homogeneous coordinates.
+	NonHomogeneous,
+	/// A divisor vector has a magnitude of zero.
+	ZeroMagnitude,
+	/// The operation requires a square matrix.
+	NotSquare { rows: usize, columns: usize },
+	/// The matrix is singular, or too close to singular for the tolerance.
+	Singular,
+	/// The system has fewer equations, rows, than unknowns, columns.
+	Underdetermined { rows: usize, columns: usize },
+	/// The operation requires a symmetric matrix, but the entry at `row`,
+	/// `column` differs from its mirror.
+	NotSymmetric { row: usize, column: usize },
+	/// An iterative algorithm did not converge within `iterations` steps.
+	NotConverged { iterations: usize },
+	/// The operation requires a symmetric positive definite matrix.
+	NotPositiveDefinite,
+	/// The position `row`, `column` lies outside a matrix of `shape`.
+	IndexOutOfBounds {
+		row: usize,
+		column: usize,
+		shape: (usize, usize),
+	},
+	/// The linear operator cannot apply its transpose.
+	TransposeUnavailable,
+}
+
+impl fmt::Display for MatrixError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			MatrixError::DimensionMismatch { lhs, rhs } => write!(
+				f,
+				"dimensions do not match: {}x{} and {}x{}",
+				lhs.0, lhs.1, rhs.0, rhs.1
+			),
+			MatrixError::EmptyOperand => write!(f, "operand has zero elements"),
+			MatrixError::RaggedRows {
+				row,
+				expected,
+				found,
+			} => write!(
+				f,
+				"row {} has {} columns but {} were expected",
+				row, found, expected
+			),
+			MatrixError::NonHomogeneous => write!(f, "input is not in homogeneous coordinates"),
+			MatrixError::ZeroMagnitude => write!(f, "cannot divide by a vector of zero magnitude"),
+			MatrixError::NotSquare { rows, columns } => {
+				write!(f, "expected a square matrix but found {}x{}", rows, columns)
+			}
+			MatrixError::Singular => write!(f, "matrix is singular"),
+			MatrixError::Underdetermined { rows, columns } => write!(
+				f,
+				"expected at least as many rows as columns but found {}x{}",
+				rows, columns
+			),
+			MatrixError::NotSymmetric { row, column } => write!(
+				f,
+				"matrix is not symmetric at row {}, column {}",
+				row, column
+			),
+			MatrixError::NotConverged { iterations } => {
+				write!(f, "did not converge within {} iterations", iterations)
+			}
+			MatrixError::NotPositiveDefinite => write!(f, "matrix is not positive definite"),
+			MatrixError::IndexOutOfBounds { row, column, shape } => write!(
+				f,
+				"position ({}, {}) is outside a {}x{} matrix",
+				row, column, shape.0, shape.1
+			),
+			MatrixError::TransposeUnavailable => {
+				write!(f, "linear operator does not provide its transpose")
+			}
+		}
+	}
+}
+
+impl std::error::Error for MatrixError {}
@@ -0,0 +1,254 @@
+use crate::{check_operand_length, matrix_shape, LinearOperator, MatrixError};
+use num::{One, Zero};
+use std::ops::{Add, Mul};
+
+/// A stack allocated matrix of `R` rows and `C` columns.
+///
+/// Shapes are part of the type, so multiplying a `Matrix<T, R, K>` by a
+/// `Matrix<T, K, C>` only compiles when the inner dimensions agree.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Matrix<T, const R: usize, const C: usize>(pub [[T; C]; R]);
+
+/// A stack allocated column vector of `N` elements.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Vector<T, const N: usize>(pub [T; N]);
+
+impl<T, const R: usize, const C: usize> Matrix<T, R, C>
+where
+	T: Add<T, Output = T> + Mul<T, Output = T> + Zero + Copy,
+{
+	pub fn new(data: [[T; C]; R]) -> Self {
+		Matrix(data)
+	}
+	pub fn zeros() -> Self {
+		Matrix([[T::zero(); C]; R])
+	}
+	pub fn add(&self, rhs: &Matrix<T, R, C>) -> Matrix<T, R, C> {
+		Matrix(std::array::from_fn(|row| {
+			std::array::from_fn(|column| self.0[row][column] + rhs.0[row][column])
+		}))
+	}
+	pub fn mul<const K: usize>(&self, rhs: &Matrix<T, C, K>) -> Matrix<T, R, K> {
+		Matrix(std::array::from_fn(|row| {
+			std::array::from_fn(|column| {
+				let mut temp = T::zero();
+				for index in 0..C {
+					temp = temp + self.0[row][index] * rhs.0[index][column];
+				}
+				temp
+			})
+		}))
+	}
+	pub fn mul_vector(&self, rhs: &Vector<T, C>) -> Vector<T, R> {
+		Vector(std::array::from_fn(|row| {
+			let mut temp = T::zero();
+			for column in 0..C {
+				temp = temp + self.0[row][column] * rhs.0[column];
+			}
+			temp
+		}))
+	}
+	pub fn scale(&self, rhs: T) -> Matrix<T, R, C> {
+		Matrix(std::array::from_fn(|row| {
+			std::array::from_fn(|column| self.0[row][column] * rhs)
+		}))
+	}
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+	T: Add<T, Output = T> + Mul<T, Output = T> + Zero + One + Copy,
+{
+	pub fn identity() -> Self {
+		Matrix(std::array::from_fn(|row| {
+			std::array::from_fn(|column| if row == column { T::one() } else { T::zero() })
+		}))
+	}
+}
+
+impl<T, const N: usize> Vector<T, N>
+where
+	T: Add<T, Output = T> + Mul<T, Output = T> + Zero + Copy,
+{
+	pub fn new(data: [T; N]) -> Self {
+		Vector(data)
+	}
+	pub fn zeros() -> Self {
+		Vector([T::zero(); N])
+	}
+	pub fn add(&self, rhs: &Vector<T, N>) -> Vector<T, N> {
+		Vector(std::array::from_fn(|index| self.0[index] + rhs.0[index]))
+	}
+	pub fn scale(&self, rhs: T) -> Vector<T, N> {
+		Vector(std::array::from_fn(|index| self.0[index] * rhs))
+	}
+	pub fn dot(&self, rhs: &Vector<T, N>) -> T {
+		let mut sum = T::zero();
+		for index in 0..N {
+			sum = sum + self.0[index] * rhs.0[index];
+		}
+		sum
+	}
+}
+
+impl<T, const R: usize, const C: usize> From<[[T; C]; R]> for Matrix<T, R, C> {
+	fn from(data: [[T; C]; R]) -> Self {
+		Matrix(data)
+	}
+}
+
+impl<T, const R: usize, const C: usize> From<Matrix<T, R, C>> for [[T; C]; R] {
+	fn from(matrix: Matrix<T, R, C>) -> Self {
+		matrix.0
+	}
+}
+
+impl<T, const N: usize> From<[T; N]> for Vector<T, N> {
+	fn from(data: [T; N]) -> Self {
+		Vector(data)
+	}
+}
+
+impl<T, const N: usize> From<Vector<T, N>> for [T; N] {
+	fn from(vector: Vector<T, N>) -> Self {
+		vector.0
+	}
+}
+
+impl<T: Copy, const R: usize, const C: usize> From<Matrix<T, R, C>> for Vec<Vec<T>> {
+	fn from(matrix: Matrix<T, R, C>) -> Self {
+		matrix.0.iter().map(|row| row.to_vec()).collect()
+	}
+}
+
+impl<T: Copy, const N: usize> From<Vector<T, N>> for Vec<T> {
+	fn from(vector: Vector<T, N>) -> Self {
+		vector.0.to_vec()
+	}
+}
+
+impl<T: Copy, const R: usize, const C: usize> TryFrom<&Vec<Vec<T>>> for Matrix<T, R, C> {
+	type Error = MatrixError;
+
+	fn try_from(matrix: &Vec<Vec<T>>) -> Result<Self, MatrixError> {
+		let shape = matrix_shape(matrix)?;
+		if shape != (R, C) {
+			return Err(MatrixError::DimensionMismatch {
+				lhs: shape,
+				rhs: (R, C),
+			});
+		}
+		Ok(Matrix(std::array::from_fn(|row| {
+			std::array::from_fn(|column| matrix[row][column])
+		})))
+	}
+}
+
+impl<T: Copy, const N: usize> TryFrom<&Vec<T>> for Vector<T, N> {
+	type Error = MatrixError;
+
+	fn try_from(vector: &Vec<T>) -> Result<Self, MatrixError> {
+		if vector.len() != N {
+			return Err(MatrixError::DimensionMismatch {
+				lhs: (vector.len(), 1),
+				rhs: (N, 1),
+			});
+		}
+		Ok(Vector(std::array::from_fn(|index| vector[index])))
+	}
+}
+
+impl<T, const R: usize, const C: usize> LinearOperator<T> for Matrix<T, R, C>
+where
+	T: Add<T, Output = T> + Mul<T, Output = T> + Zero + Copy,
+{
+	fn try_apply(&self, x: &[T]) -> Result<Vec<T>, MatrixError> {
+		check_operand_length((R, C), C, x)?;
+		let x: &[T; C] = x.try_into().unwrap();
+		Ok(self.mul_vector(&Vector(*x)).0.to_vec())
+	}
+	fn try_apply_transpose(&self, x: &[T]) -> Result<Vec<T>, MatrixError> {
+		check_operand_length((R, C), R, x)?;
+		Ok((0..C)
+			.map(|column| {
+				self.0
+					.iter()
+					.zip(x.iter())
+					.fold(T::zero(), |sum, (row, x)| sum + row[column] * *x)
+			})
+			.collect())
+	}
+}
+
+#[cfg(test)]
+mod test_fixed_size {
+	use crate::{Fsize, LinearOperator, Matrix, MatrixError, Vector, VectorMD, VectorSD, I4};
+
+	#[test]
+	fn test_matrix_mul() {
+		let matrix_a: Matrix<Fsize, 2, 3> = Matrix::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+		let matrix_b: Matrix<Fsize, 3, 2> = Matrix::new([[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]]);
+
+		assert_eq!(
+			Matrix::new([[4.0, 5.0], [10.0, 11.0]]),
+			matrix_a.mul(&matrix_b)
+		);
+		assert_eq!(
+			Vector::new([6.0, 15.0]),
+			matrix_a.mul_vector(&Vector::new([1.0, 1.0, 1.0]))
+		);
+		assert_eq!(
+			Matrix::new([[2.0, 4.0, 6.0], [8.0, 10.0, 12.0]]),
+			matrix_a.add(&matrix_a)
+		);
+		assert_eq!(matrix_a.add(&matrix_a), matrix_a.scale(2.0));
+		assert_eq!(Matrix::from(I4), Matrix::<Fsize, 4, 4>::identity());
+	}
+	#[test]
+	fn test_vector_ops() {
+		let vec_a: Vector<Fsize, 3> = Vector::new([1.0, 2.0, 3.0]);
+		let vec_b: Vector<Fsize, 3> = Vector::new([3.0, 4.0, 4.0]);
+
+		assert_eq!(23.0, vec_a.dot(&vec_b));
+		assert_eq!(Vector::new([4.0, 6.0, 7.0]), vec_a.add(&vec_b));
+		assert_eq!(Vector::new([2.0, 4.0, 6.0]), vec_a.scale(2.0));
+	}
+	#[test]
+	fn test_conversions() {
+		let matrix_a: VectorMD = vec![vec![1.0, 2.0], vec![3.0, 4.0]];
+		let vec_a: VectorSD = vec![1.0, 2.0, 3.0];
+
+		let fixed_matrix = Matrix::<Fsize, 2, 2>::try_from(&matrix_a).unwrap();
+		let fixed_vec = Vector::<Fsize, 3>::try_from(&vec_a).unwrap();
+		assert_eq!(matrix_a, VectorMD::from(fixed_matrix));
+		assert_eq!(vec_a, VectorSD::from(fixed_vec));
+		assert_eq!(
+			Err(MatrixError::DimensionMismatch {
+				lhs: (2, 2),
+				rhs: (3, 2)
+			}),
+			Matrix::<Fsize, 3, 2>::try_from(&matrix_a)
+		);
+		assert_eq!(
+			Err(MatrixError::DimensionMismatch {
+				lhs: (3, 1),
+				rhs: (4, 1)
+			}),
+			Vector::<Fsize, 4>::try_from(&vec_a)
+		);
+	}
+	#[test]
+	fn test_matrix_linear_operator() {
+		let matrix: Matrix<Fsize, 2, 3> = Matrix::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+		assert_eq!(vec![14.0, 32.0], matrix.apply(&[1.0, 2.0, 3.0]));
+		assert_eq!(vec![-3.0, -3.0, -3.0], matrix.apply_transpose(&[1.0, -1.0]));
+		assert_eq!(
+			Err(MatrixError::DimensionMismatch {
+				lhs: (2, 3),
+				rhs: (2, 1)
+			}),
+			matrix.try_apply(&[1.0, 2.0])
+		);
+	}
+}
@@ -0,0 +1,143 @@
+use super::solver::{axpy, dot, mult_square, norm, trivial_solution};
+use super::{Preconditioner, SolverOptions, SolverResult};
+use crate::{LinearOperator, MatrixError};
+use num::Float;
+
+/// Solves `a·x = b` for a general square `a` with the right preconditioned
+/// BiCGSTAB method, starting from `x = 0`.
+///
+/// Each iteration takes two products with `a` and records two residuals. A
+/// breakdown, where the shadow residual becomes orthogonal to the residual,
+/// ends the solve early without converging.
+pub fn bicgstab<T, A, P>(
+	a: &A,
+	b: &[T],
+	preconditioner: &P,
+	options: SolverOptions<T>,
+) -> Result<SolverResult<T>, MatrixError>
+where
+	T: Float,
+	A: LinearOperator<T> + ?Sized,
+	P: Preconditioner<T> + ?Sized,
+{
+	if let Some(result) = trivial_solution(b)? {
+		return Ok(result);
+	}
+	let b_norm = norm(b);
+	let size = b.len();
+	let mut x = vec![T::zero(); size];
+	let mut r = b.to_vec();
+	let shadow = b.to_vec();
+	let (mut rho, mut alpha, mut omega) = (T::one(), T::one(), T::one());
+	let mut p = vec![T::zero(); size];
+	let mut v = vec![T::zero(); size];
+	let mut residual_history = vec![T::one()];
+	let mut iterations = 0;
+
+	let result = |solution, iterations, residual_history, converged| SolverResult {
+		solution,
+		iterations,
+		residual_history,
+		converged,
+	};
+	while iterations < options.max_iterations {
+		let rho_next = dot(&shadow, &r);
+		if rho_next == T::zero() || omega == T::zero() {
+			break;
+		}
+		let beta = (rho_next / rho) * (alpha / omega);
+		rho = rho_next;
+		for ((p, r), v) in p.iter_mut().zip(r.iter()).zip(v.iter()) {
+			*p = *r + beta * (*p - omega * *v);
+		}
+		let p_hat = preconditioner.try_precondition(&p)?;
+		v = mult_square(a, &p_hat)?;
+		let shadow_v = dot(&shadow, &v);
+		if shadow_v == T::zero() {
+			break;
+		}
+		alpha = rho / shadow_v;
+		axpy(alpha, &p_hat, &mut x);
+		axpy(-alpha, &v, &mut r);
+		iterations += 1;
+		let residual = norm(&r) / b_norm;
+		residual_history.push(residual);
+		if residual <= options.tolerance {
+			return Ok(result(x, iterations, residual_history, true));
+		}
+		if iterations == options.max_iterations {
+			break;
+		}
+
+		let s_hat = preconditioner.try_precondition(&r)?;
+		let t = mult_square(a, &s_hat)?;
+		let t_t = dot(&t, &t);
+		omega = if t_t == T::zero() {
+			T::zero()
+		} else {
+			dot(&t, &r) / t_t
+		};
+		axpy(omega, &s_hat, &mut x);
+		axpy(-omega, &t, &mut r);
+		iterations += 1;
+		let residual = norm(&r) / b_norm;
+		residual_history.push(residual);
+		if residual <= options.tolerance {
+			return Ok(result(x, iterations, residual_history, true));
+		}
+	}
+	Ok(result(x, iterations, residual_history, false))
+}
+
+#[cfg(test)]
+mod test_bicgstab {
+	use super::bicgstab;
+	use crate::iterative::{IdentityPreconditioner, JacobiPreconditioner, SolverOptions};
+	use crate::sparse::{CooMatrix, CsrMatrix};
+	use crate::{Fsize, SparseOperations};
+
+	//a 1D convection diffusion operator, which is not symmetric
+	fn convection_diffusion(size: usize) -> CsrMatrix<Fsize> {
+		let mut matrix = CooMatrix::new(size, size);
+		for index in 0..size {
+			matrix.push(index, index, 2.0 + index as Fsize * 0.1);
+			if index + 1 < size {
+				matrix.push(index, index + 1, -0.6);
+				matrix.push(index + 1, index, -1.4);
+			}
+		}
+		matrix.to_csr()
+	}
+
+	#[test]
+	fn test_bicgstab() {
+		let matrix = convection_diffusion(50);
+		let expected: Vec<Fsize> = (0..50).map(|index| (index as Fsize * 0.2).cos()).collect();
+		let b = matrix.sparse_mult_vector(&expected);
+		let options = SolverOptions::new(1e-10, 200);
+		let jacobi = JacobiPreconditioner::new(&matrix.diagonal()).unwrap();
+
+		for result in [
+			bicgstab(&matrix, &b, &IdentityPreconditioner, options).unwrap(),
+			bicgstab(&matrix, &b, &jacobi, options).unwrap(),
+		]
+		.iter()
+		{
+			assert!(result.converged);
+			assert_eq!(result.iterations + 1, result.residual_history.len());
+			for (expected, solution) in expected.iter().zip(result.solution.iter()) {
+				assert!((expected - solution).abs() < 1e-8);
+			}
+		}
+
+		let limited = bicgstab(
+			&matrix,
+			&b,
+			&IdentityPreconditioner,
+			SolverOptions::new(1e-12, 3),
+		)
+		.unwrap();
+		assert!(!limited.converged);
+		assert_eq!(3, limited.iterations);
+	}
+}
@@ -0,0 +1,175 @@
+use super::solver::{axpy, dot, mult_square, norm, trivial_solution};
+use super::{Preconditioner, SolverOptions, SolverResult};
+use crate::{LinearOperator, MatrixError};
+use num::Float;
+
+/// Solves `a·x = b` for a symmetric positive definite `a` with the
+/// preconditioned conjugate gradient method, starting from `x = 0`.
+///
+/// The preconditioner must be symmetric positive definite as well. Fails with
+/// `NotPositiveDefinite` when a search direction reveals `a` is not.
+pub fn conjugate_gradient<T, A, P>(
+	a: &A,
+	b: &[T],
+	preconditioner: &P,
+	options: SolverOptions<T>,
+) -> Result<SolverResult<T>, MatrixError>
+where
+	T: Float,
+	A: LinearOperator<T> + ?Sized,
+	P: Preconditioner<T> + ?Sized,
+{
+	if let Some(result) = trivial_solution(b)? {
+		return Ok(result);
+	}
+	let b_norm = norm(b);
+	let mut x = vec![T::zero(); b.len()];
+	let mut r = b.to_vec();
+	let mut z = preconditioner.try_precondition(&r)?;
+	let mut p = z.clone();
+	let mut rz = dot(&r, &z);
+	let mut residual_history = vec![T::one()];
+
+	for iteration in 1..=options.max_iterations {
+		let q = mult_square(a, &p)?;
+		let curvature = dot(&p, &q);
+		if curvature <= T::zero() {
+			return Err(MatrixError::NotPositiveDefinite);
+		}
+		let alpha = rz / curvature;
+		axpy(alpha, &p, &mut x);
+		axpy(-alpha, &q, &mut r);
+		let residual = norm(&r) / b_norm;
+		residual_history.push(residual);
+		if residual <= options.tolerance {
+			return Ok(SolverResult {
+				solution: x,
+				iterations: iteration,
+				residual_history,
+				converged: true,
+			});
+		}
+
+		z = preconditioner.try_precondition(&r)?;
+		let rz_next = dot(&r, &z);
+		let beta = rz_next / rz;
+		rz = rz_next;
+		p.iter_mut()
+			.zip(z.iter())
+			.for_each(|(p, z)| *p = *z + beta * *p);
+	}
+	Ok(SolverResult {
+		solution: x,
+		iterations: options.max_iterations,
+		residual_history,
+		converged: false,
+	})
+}
+
+#[cfg(test)]
+mod test_conjugate_gradient {
+	use super::conjugate_gradient;
+	use crate::iterative::{
+		IdentityPreconditioner, IncompleteCholesky, JacobiPreconditioner, SolverOptions,
+	};
+	use crate::sparse::{CooMatrix, CsrMatrix};
+	use crate::{Fsize, MatrixError, SparseOperations};
+
+	//a 1D diffusion operator with a coefficient varying along the domain
+	fn diffusion(size: usize) -> CsrMatrix<Fsize> {
+		let mut matrix = CooMatrix::new(size, size);
+		for index in 0..size {
+			let coefficient = 1.0 + index as Fsize;
+			matrix.push(index, index, 2.0 * coefficient);
+			if index + 1 < size {
+				matrix.push(index, index + 1, -1.0);
+				matrix.push(index + 1, index, -1.0);
+			}
+		}
+		matrix.to_csr()
+	}
+
+	#[test]
+	fn test_conjugate_gradient() {
+		let matrix = diffusion(40);
+		let expected: Vec<Fsize> = (0..40).map(|index| (index as Fsize * 0.3).sin()).collect();
+		let b = matrix.sparse_mult_vector(&expected);
+		let options = SolverOptions::new(1e-10, 100);
+
+		let plain = conjugate_gradient(&matrix, &b, &IdentityPreconditioner, options).unwrap();
+		let jacobi = JacobiPreconditioner::new(&matrix.diagonal()).unwrap();
+		let preconditioned = conjugate_gradient(&matrix, &b, &jacobi, options).unwrap();
+		let cholesky = IncompleteCholesky::new(&matrix).unwrap();
+		let exact = conjugate_gradient(&matrix, &b, &cholesky, options).unwrap();
+
+		for result in [&plain, &preconditioned, &exact].iter() {
+			assert!(result.converged);
+			assert_eq!(result.iterations + 1, result.residual_history.len());
+			for (expected, solution) in expected.iter().zip(result.solution.iter()) {
+				assert!((expected - solution).abs() < 1e-8);
+			}
+		}
+		assert!(preconditioned.iterations < plain.iterations);
+		// incomplete Cholesky of a tridiagonal matrix is exact
+		assert_eq!(1, exact.iterations);
+	}
+	#[test]
+	fn test_conjugate_gradient_limits() {
+		let matrix = diffusion(40);
+		let b = vec![1.0; 40];
+
+		let result = conjugate_gradient(
+			&matrix,
+			&b,
+			&IdentityPreconditioner,
+			SolverOptions::new(1e-12, 3),
+		)
+		.unwrap();
+		assert!(!result.converged);
+		assert_eq!(3, result.iterations);
+		assert_eq!(4, result.residual_history.len());
+
+		let indefinite: Vec<Vec<Fsize>> = vec![vec![1.0, 0.0], vec![0.0, -1.0]];
+		assert_eq!(
+			Err(MatrixError::NotPositiveDefinite),
+			conjugate_gradient(
+				&indefinite,
+				&[0.0, 1.0],
+				&IdentityPreconditioner,
+				SolverOptions::new(1e-12, 10)
+			)
+		);
+		assert_eq!(
+			Err(MatrixError::DimensionMismatch {
+				lhs: (40, 40),
+				rhs: (2, 1)
+			}),
+			conjugate_gradient(
+				&matrix,
+				&[1.0, 1.0],
+				&IdentityPreconditioner,
+				SolverOptions::new(1e-12, 10)
+			)
+		);
+	}
+	#[test]
+	fn test_conjugate_gradient_matrix_free() {
+		let matrix = diffusion(20);
+		let stencil = |x: &[Fsize]| -> Vec<Fsize> {
+			(0..x.len())
+				.map(|index| {
+					let left = if index == 0 { 0.0 } else { x[index - 1] };
+					let right = x.get(index + 1).copied().unwrap_or(0.0);
+					2.0 * (1.0 + index as Fsize) * x[index] - left - right
+				})
+				.collect()
+		};
+		let b = vec![1.0; 20];
+		let options = SolverOptions::new(1e-10, 100);
+
+		assert_eq!(
+			conjugate_gradient(&matrix, &b, &IdentityPreconditioner, options),
+			conjugate_gradient(&stencil, &b, &IdentityPreconditioner, options)
+		);
+	}
+}
@@ -0,0 +1,206 @@
+use super::solver::{axpy, dot, mult_square, norm, trivial_solution};
+use super::{Preconditioner, SolverOptions, SolverResult};
+use crate::{LinearOperator, MatrixError};
+use num::Float;
+
+/// Solves `a·x = b` for a general square `a` with right preconditioned
+/// GMRES, restarted every `restart` iterations, starting from `x = 0`.
+///
+/// Memory grows with `restart`, which trades storage for robustness: GMRES
+/// without restarts, `restart >= b.len()`, converges in at most `b.len()`
+/// iterations in exact arithmetic.
+///
+/// Fails with `EmptyOperand` when `restart` is 0, as the Krylov space then
+/// has no basis vectors. A singular `a` can make the triangular factor of
+/// the Hessenberg matrix lose rank, in which case the solve stops early and
+/// the result is reported as not converged.
+pub fn gmres<T, A, P>(
+	a: &A,
+	b: &[T],
+	restart: usize,
+	preconditioner: &P,
+	options: SolverOptions<T>,
+) -> Result<SolverResult<T>, MatrixError>
+where
+	T: Float,
+	A: LinearOperator<T> + ?Sized,
+	P: Preconditioner<T> + ?Sized,
+{
+	if restart == 0 {
+		return Err(MatrixError::EmptyOperand);
+	}
+	if let Some(result) = trivial_solution(b)? {
+		return Ok(result);
+	}
+	let b_norm = norm(b);
+	let mut x = vec![T::zero(); b.len()];
+	let mut residual_history = vec![T::one()];
+	let mut iterations = 0;
+	let mut converged = false;
+	let mut breakdown = false;
+
+	while iterations < options.max_iterations && !converged && !breakdown {
+		let ax = mult_square(a, &x)?;
+		let r: Vec<T> = b.iter().zip(ax.iter()).map(|(b, ax)| *b - *ax).collect();
+		let beta = norm(&r);
+		if beta / b_norm <= options.tolerance {
+			break;
+		}
+
+		// Arnoldi on the Krylov space of a·M⁻¹, with the Hessenberg matrix
+		// reduced to triangular by Givens rotations as it grows
+		let mut basis: Vec<Vec<T>> = vec![r.iter().map(|value| *value / beta).collect()];
+		let mut preconditioned: Vec<Vec<T>> = Vec::with_capacity(restart);
+		let mut hessenberg: Vec<Vec<T>> = Vec::with_capacity(restart);
+		let mut rotations: Vec<(T, T)> = Vec::with_capacity(restart);
+		let mut g = vec![beta];
+		for _ in 0..restart {
+			let z = preconditioner.try_precondition(&basis[basis.len() - 1])?;
+			let mut w = mult_square(a, &z)?;
+			preconditioned.push(z);
+			let mut column: Vec<T> = Vec::with_capacity(basis.len() + 1);
+			for vector in basis.iter() {
+				let h = dot(&w, vector);
+				axpy(-h, vector, &mut w);
+				column.push(h);
+			}
+			let w_norm = norm(&w);
+			column.push(w_norm);
+
+			for (index, (cos, sin)) in rotations.iter().enumerate() {
+				let (upper, lower) = (column[index], column[index + 1]);
+				column[index] = *cos * upper + *sin * lower;
+				column[index + 1] = *cos * lower - *sin * upper;
+			}
+			let last = column.len() - 1;
+			let hypotenuse = column[last - 1].hypot(column[last]);
+			// a zero diagonal in the triangular factor, the new column adds
+			// nothing and the solution is taken from the columns before it
+			if hypotenuse == T::zero() {
+				breakdown = true;
+				break;
+			}
+			let (cos, sin) = (column[last - 1] / hypotenuse, column[last] / hypotenuse);
+			column[last - 1] = hypotenuse;
+			column.pop();
+			rotations.push((cos, sin));
+			let g_last = g[last - 1];
+			g[last - 1] = cos * g_last;
+			g.push(-sin * g_last);
+			hessenberg.push(column);
+
+			iterations += 1;
+			let residual = g[last].abs() / b_norm;
+			residual_history.push(residual);
+			converged = residual <= options.tolerance;
+			// a zero w means the Krylov space is invariant and holds the solution
+			if converged || w_norm == T::zero() || iterations == options.max_iterations {
+				break;
+			}
+			basis.push(w.iter().map(|value| *value / w_norm).collect());
+		}
+
+		// back substitution for the coefficients of the preconditioned basis
+		let size = hessenberg.len();
+		let mut y = g[..size].to_vec();
+		for row in (0..size).rev() {
+			for column in row + 1..size {
+				y[row] = y[row] - hessenberg[column][row] * y[column];
+			}
+			y[row] = y[row] / hessenberg[row][row];
+		}
+		for (coefficient, z) in y.iter().zip(preconditioned.iter()) {
+			axpy(*coefficient, z, &mut x);
+		}
+	}
+	Ok(SolverResult {
+		solution: x,
+		iterations,
+		residual_history,
+		converged,
+	})
+}
+
+#[cfg(test)]
+mod test_gmres {
+	use super::gmres;
+	use crate::iterative::{IdentityPreconditioner, JacobiPreconditioner, SolverOptions};
+	use crate::sparse::CooMatrix;
+	use crate::{Fsize, MatrixError, SparseOperations};
+
+	#[test]
+	fn test_gmres() {
+		let size = 40;
+		let mut coo = CooMatrix::new(size, size);
+		for index in 0..size {
+			coo.push(index, index, 3.0 + (index % 5) as Fsize);
+			if index + 1 < size {
+				coo.push(index, index + 1, -0.5);
+				coo.push(index + 1, index, -2.0);
+			}
+			if index + 7 < size {
+				coo.push(index, index + 7, 0.3);
+			}
+		}
+		let matrix = coo.to_csr();
+		let expected: Vec<Fsize> = (0..size)
+			.map(|index| 1.0 / (1.0 + index as Fsize))
+			.collect();
+		let b = matrix.sparse_mult_vector(&expected);
+		let options = SolverOptions::new(1e-10, 400);
+		let jacobi = JacobiPreconditioner::new(&matrix.diagonal()).unwrap();
+
+		let full = gmres(&matrix, &b, size, &IdentityPreconditioner, options).unwrap();
+		let restarted = gmres(&matrix, &b, 10, &jacobi, options).unwrap();
+		for result in [&full, &restarted].iter() {
+			assert!(result.converged);
+			assert_eq!(result.iterations + 1, result.residual_history.len());
+			for (expected, solution) in expected.iter().zip(result.solution.iter()) {
+				assert!((expected - solution).abs() < 1e-8);
+			}
+		}
+		assert!(full.iterations <= size);
+
+		let limited = gmres(
+			&matrix,
+			&b,
+			5,
+			&IdentityPreconditioner,
+			SolverOptions::new(1e-12, 12),
+		)
+		.unwrap();
+		assert!(!limited.converged);
+		assert_eq!(12, limited.iterations);
+		assert!(limited.residual_history[12] < limited.residual_history[0]);
+		assert_eq!(
+			Err(MatrixError::NotSquare {
+				rows: 2,
+				columns: 3
+			}),
+			gmres(
+				&vec![vec![1.0, 0.0, 0.0], vec![0.0, 1.0, 0.0]],
+				&[1.0, 1.0, 1.0],
+				3,
+				&IdentityPreconditioner,
+				options
+			)
+		);
+		assert_eq!(
+			Err(MatrixError::EmptyOperand),
+			gmres(&matrix, &b, 0, &IdentityPreconditioner, options)
+		);
+	}
+	#[test]
+	fn test_gmres_singular() {
+		let options = SolverOptions::new(1e-10, 20);
+		let zero: Vec<Vec<Fsize>> = vec![vec![0.0, 0.0], vec![0.0, 0.0]];
+		let rank_one: Vec<Vec<Fsize>> = vec![vec![1.0, 0.0], vec![0.0, 0.0]];
+
+		for matrix in [zero, rank_one].iter() {
+			let result = gmres(matrix, &[1.0, 1.0], 2, &IdentityPreconditioner, options).unwrap();
+			assert!(!result.converged);
+			assert!(result.solution.iter().all(|value| value.is_finite()));
+			assert_eq!(result.iterations + 1, result.residual_history.len());
+		}
+	}
+}
@@ -0,0 +1,15 @@
+mod bicgstab;
+mod conjugate_gradient;
+mod gmres;
+mod power_iteration;
+mod preconditioner;
+mod solver;
+
+pub use bicgstab::bicgstab;
+pub use conjugate_gradient::conjugate_gradient;
+pub use gmres::gmres;
+pub use power_iteration::{power_iteration, DominantEigen};
+pub use preconditioner::{
+	IdentityPreconditioner, IncompleteCholesky, JacobiPreconditioner, Preconditioner,
+};
+pub use solver::{SolverOptions, SolverResult};
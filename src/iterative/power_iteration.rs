@@ -0,0 +1,112 @@
+use super::solver::{dot, mult_square, norm};
+use super::SolverOptions;
+use crate::{LinearOperator, MatrixError};
+use num::Float;
+
+/// The eigenvalue of largest magnitude and its unit eigenvector.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DominantEigen<T> {
+	pub eigenvalue: T,
+	pub eigenvector: Vec<T>,
+	pub iterations: usize,
+	pub converged: bool,
+}
+
+/// Finds the dominant eigenpair of `a` by repeated application, starting
+/// from `initial`, which also fixes the size of the problem.
+///
+/// Converges once `|a·v - λ·v| <= tolerance·|λ|`, at a rate set by the ratio
+/// of the two largest eigenvalue magnitudes. `initial` must not be orthogonal
+/// to the dominant eigenvector.
+pub fn power_iteration<T, A>(
+	a: &A,
+	initial: &[T],
+	options: SolverOptions<T>,
+) -> Result<DominantEigen<T>, MatrixError>
+where
+	T: Float,
+	A: LinearOperator<T> + ?Sized,
+{
+	if initial.is_empty() {
+		return Err(MatrixError::EmptyOperand);
+	}
+	let initial_norm = norm(initial);
+	if initial_norm == T::zero() {
+		return Err(MatrixError::ZeroMagnitude);
+	}
+	let mut v: Vec<T> = initial.iter().map(|value| *value / initial_norm).collect();
+	let mut eigenvalue = T::zero();
+
+	for iteration in 1..=options.max_iterations {
+		let w = mult_square(a, &v)?;
+		eigenvalue = dot(&v, &w);
+		let residual = w
+			.iter()
+			.zip(v.iter())
+			.fold(T::zero(), |sum, (w, v)| {
+				let difference = *w - eigenvalue * *v;
+				sum + difference * difference
+			})
+			.sqrt();
+		if residual <= options.tolerance * eigenvalue.abs() {
+			return Ok(DominantEigen {
+				eigenvalue,
+				eigenvector: v,
+				iterations: iteration,
+				converged: true,
+			});
+		}
+		let w_norm = norm(&w);
+		v = w.iter().map(|value| *value / w_norm).collect();
+	}
+	Ok(DominantEigen {
+		eigenvalue,
+		eigenvector: v,
+		iterations: options.max_iterations,
+		converged: false,
+	})
+}
+
+#[cfg(test)]
+mod test_power_iteration {
+	use super::power_iteration;
+	use crate::iterative::SolverOptions;
+	use crate::{Fsize, LinearOperator, MatrixError};
+
+	#[test]
+	fn test_power_iteration() {
+		let matrix: Vec<Vec<Fsize>> = vec![vec![2.0, 1.0], vec![1.0, 3.0]];
+		let result = power_iteration(&matrix, &[1.0, 0.0], SolverOptions::new(1e-10, 500)).unwrap();
+		let expected = (5.0 + (5.0 as Fsize).sqrt()) / 2.0;
+
+		assert!(result.converged);
+		assert!((expected - result.eigenvalue).abs() < 1e-9);
+		let image = matrix.apply(&result.eigenvector);
+		for (image, vector) in image.iter().zip(result.eigenvector.iter()) {
+			assert!((image - expected * vector).abs() < 1e-8);
+		}
+
+		// a matrix free 1D Laplacian, whose largest eigenvalue tends to 4
+		let laplacian = |x: &[Fsize]| -> Vec<Fsize> {
+			(0..x.len())
+				.map(|index| {
+					let left = if index == 0 { 0.0 } else { x[index - 1] };
+					let right = x.get(index + 1).copied().unwrap_or(0.0);
+					2.0 * x[index] - left - right
+				})
+				.collect()
+		};
+		let initial: Vec<Fsize> = (0..10)
+			.map(|index| if index % 2 == 0 { 1.0 } else { -1.0 })
+			.collect();
+		let result = power_iteration(&laplacian, &initial, SolverOptions::new(1e-8, 2000)).unwrap();
+		let pi = std::f64::consts::PI as Fsize;
+		assert!(result.converged);
+		assert!((2.0 - 2.0 * (10.0 * pi / 11.0).cos() - result.eigenvalue).abs() < 1e-6);
+
+		assert_eq!(
+			Err(MatrixError::ZeroMagnitude),
+			power_iteration(&matrix, &[0.0, 0.0], SolverOptions::new(1e-10, 10))
+		);
+	}
+}
@@ -0,0 +1,202 @@
+use crate::sparse::CsrMatrix;
+use crate::MatrixError;
+use num::Float;
+
+/// An approximation `M` of the system matrix whose inverse is cheap to apply.
+pub trait Preconditioner<T> {
+	/// Computes `M⁻¹·r`.
+	fn try_precondition(&self, r: &[T]) -> Result<Vec<T>, MatrixError>;
+
+	fn precondition(&self, r: &[T]) -> Vec<T> {
+		self.try_precondition(r)
+			.unwrap_or_else(|err| panic!("{}", err))
+	}
+}
+
+/// Leaves the residual unchanged, for unpreconditioned solves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdentityPreconditioner;
+
+/// Divides by the diagonal of the system matrix.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JacobiPreconditioner<T> {
+	inverse_diagonal: Vec<T>,
+}
+
+/// Zero fill incomplete Cholesky, `M = L·Lᵀ` with `L` restricted to the
+/// sparsity pattern of the lower triangle of a symmetric positive definite
+/// matrix.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IncompleteCholesky<T> {
+	//the (column, value) entries of each row of L, sorted, ending with the diagonal
+	rows: Vec<Vec<(usize, T)>>,
+}
+
+impl<T: Copy> Preconditioner<T> for IdentityPreconditioner {
+	fn try_precondition(&self, r: &[T]) -> Result<Vec<T>, MatrixError> {
+		Ok(r.to_vec())
+	}
+}
+
+impl<T: Float> JacobiPreconditioner<T> {
+	/// Fails with `Singular` when the diagonal holds a zero.
+	pub fn new(diagonal: &[T]) -> Result<Self, MatrixError> {
+		if diagonal.is_empty() {
+			return Err(MatrixError::EmptyOperand);
+		} else if diagonal.iter().any(|value| *value == T::zero()) {
+			return Err(MatrixError::Singular);
+		}
+		Ok(JacobiPreconditioner {
+			inverse_diagonal: diagonal.iter().map(|value| value.recip()).collect(),
+		})
+	}
+}
+
+impl<T: Float> Preconditioner<T> for JacobiPreconditioner<T> {
+	fn try_precondition(&self, r: &[T]) -> Result<Vec<T>, MatrixError> {
+		check_length(self.inverse_diagonal.len(), r)?;
+		Ok(r.iter()
+			.zip(self.inverse_diagonal.iter())
+			.map(|(r, inverse)| *r * *inverse)
+			.collect())
+	}
+}
+
+impl<T: Float> IncompleteCholesky<T> {
+	/// Reads only the lower triangle of `matrix`. Fails with
+	/// `NotPositiveDefinite` when a pivot is not positive, which can happen
+	/// for some positive definite matrices since fill in is dropped.
+	pub fn new(matrix: &CsrMatrix<T>) -> Result<Self, MatrixError> {
+		let (rows, columns) = matrix.shape();
+		if rows != columns {
+			return Err(MatrixError::NotSquare { rows, columns });
+		}
+		let offsets = matrix.row_offsets();
+		let mut factor: Vec<Vec<(usize, T)>> = Vec::with_capacity(rows);
+		for row in 0..rows {
+			let range = offsets[row]..offsets[row + 1];
+			let mut entries: Vec<(usize, T)> = Vec::new();
+			for (column, value) in matrix.column_indices()[range.clone()]
+				.iter()
+				.zip(matrix.values()[range].iter())
+				.filter(|(column, _)| **column < row)
+			{
+				let column_row = &factor[*column];
+				let (_, pivot) = column_row[column_row.len() - 1];
+				let entry = (*value - sparse_dot(&entries, column_row)) / pivot;
+				entries.push((*column, entry));
+			}
+			let diagonal = entries
+				.iter()
+				.fold(matrix.get(row, row), |sum, (_, value)| {
+					sum - *value * *value
+				});
+			if diagonal <= T::zero() || diagonal.is_nan() {
+				return Err(MatrixError::NotPositiveDefinite);
+			}
+			entries.push((row, diagonal.sqrt()));
+			factor.push(entries);
+		}
+		Ok(IncompleteCholesky { rows: factor })
+	}
+}
+
+impl<T: Float> Preconditioner<T> for IncompleteCholesky<T> {
+	fn try_precondition(&self, r: &[T]) -> Result<Vec<T>, MatrixError> {
+		check_length(self.rows.len(), r)?;
+		// forward substitution with L, then back substitution with Lᵀ
+		let mut z = r.to_vec();
+		for (row, entries) in self.rows.iter().enumerate() {
+			let (off_diagonal, diagonal) = entries.split_at(entries.len() - 1);
+			let sum = off_diagonal
+				.iter()
+				.fold(z[row], |sum, (column, value)| sum - *value * z[*column]);
+			z[row] = sum / diagonal[0].1;
+		}
+		for (row, entries) in self.rows.iter().enumerate().rev() {
+			let (off_diagonal, diagonal) = entries.split_at(entries.len() - 1);
+			z[row] = z[row] / diagonal[0].1;
+			let solved = z[row];
+			for (column, value) in off_diagonal.iter() {
+				z[*column] = z[*column] - *value * solved;
+			}
+		}
+		Ok(z)
+	}
+}
+
+//sums lhs[j]·rhs[j] over the indices j both sorted entry lists hold
+fn sparse_dot<T: Float>(lhs: &[(usize, T)], rhs: &[(usize, T)]) -> T {
+	let (mut left, mut right) = (0, 0);
+	let mut sum = T::zero();
+	while left < lhs.len() && right < rhs.len() {
+		match lhs[left].0.cmp(&rhs[right].0) {
+			std::cmp::Ordering::Less => left += 1,
+			std::cmp::Ordering::Greater => right += 1,
+			std::cmp::Ordering::Equal => {
+				sum = sum + lhs[left].1 * rhs[right].1;
+				left += 1;
+				right += 1;
+			}
+		}
+	}
+	sum
+}
+
+fn check_length<T>(size: usize, r: &[T]) -> Result<(), MatrixError> {
+	if r.len() != size {
+		return Err(MatrixError::DimensionMismatch {
+			lhs: (size, size),
+			rhs: (r.len(), 1),
+		});
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod test_preconditioner {
+	use super::{IncompleteCholesky, JacobiPreconditioner, Preconditioner};
+	use crate::sparse::CsrMatrix;
+	use crate::{MatrixError, SparseOperations, VectorMD};
+
+	#[test]
+	fn test_jacobi() {
+		let jacobi = JacobiPreconditioner::new(&[2.0, 4.0, -0.5]).unwrap();
+
+		assert_eq!(vec![1.0, 0.5, -4.0], jacobi.precondition(&[2.0, 2.0, 2.0]));
+		assert_eq!(
+			Err(MatrixError::Singular),
+			JacobiPreconditioner::new(&[1.0, 0.0])
+		);
+		assert_eq!(
+			Err(MatrixError::DimensionMismatch {
+				lhs: (3, 3),
+				rhs: (1, 1)
+			}),
+			jacobi.try_precondition(&[1.0])
+		);
+	}
+	#[test]
+	fn test_incomplete_cholesky() {
+		// without fill in on a tridiagonal matrix the factorization is exact
+		let dense: VectorMD = vec![
+			vec![4.0, -1.0, 0.0, 0.0],
+			vec![-1.0, 4.0, -1.0, 0.0],
+			vec![0.0, -1.0, 4.0, -1.0],
+			vec![0.0, 0.0, -1.0, 4.0],
+		];
+		let matrix = CsrMatrix::try_from(&dense).unwrap();
+		let cholesky = IncompleteCholesky::new(&matrix).unwrap();
+		let x = vec![1.0, 2.0, 3.0, 4.0];
+
+		let result = cholesky.precondition(&matrix.sparse_mult_vector(&x));
+		for (expected, result) in x.iter().zip(result.iter()) {
+			assert!((expected - result).abs() < 1e-12);
+		}
+		let indefinite = CsrMatrix::try_from(&vec![vec![1.0, 2.0], vec![2.0, 1.0]]).unwrap();
+		assert_eq!(
+			Err(MatrixError::NotPositiveDefinite),
+			IncompleteCholesky::new(&indefinite)
+		);
+	}
+}
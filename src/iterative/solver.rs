@@ -0,0 +1,85 @@
+use crate::{LinearOperator, MatrixError};
+use num::Float;
+
+/// Stopping criteria shared by the iterative solvers.
+///
+/// A solve converges once the residual norm `|b - A·x|` falls to
+/// `tolerance·|b|`, and gives up after `max_iterations` matrix vector
+/// products with `A`, counting each BiCGSTAB half step as one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolverOptions<T> {
+	pub tolerance: T,
+	pub max_iterations: usize,
+}
+
+/// The outcome of an iterative solve.
+///
+/// `residual_history` holds the relative residual norm `|b - A·x| / |b|`
+/// before the first iteration and after every iteration. A solve that runs
+/// out of iterations still returns its last iterate, with `converged` false.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolverResult<T> {
+	pub solution: Vec<T>,
+	pub iterations: usize,
+	pub residual_history: Vec<T>,
+	pub converged: bool,
+}
+
+impl<T> SolverOptions<T> {
+	pub fn new(tolerance: T, max_iterations: usize) -> Self {
+		SolverOptions {
+			tolerance,
+			max_iterations,
+		}
+	}
+}
+
+pub(super) fn dot<T: Float>(lhs: &[T], rhs: &[T]) -> T {
+	lhs.iter()
+		.zip(rhs.iter())
+		.fold(T::zero(), |sum, (lhs, rhs)| sum + *lhs * *rhs)
+}
+
+pub(super) fn norm<T: Float>(vector: &[T]) -> T {
+	dot(vector, vector).sqrt()
+}
+
+//y = y + alpha·x
+pub(super) fn axpy<T: Float>(alpha: T, x: &[T], y: &mut [T]) {
+	y.iter_mut()
+		.zip(x.iter())
+		.for_each(|(y, x)| *y = *y + alpha * *x);
+}
+
+//multiplies by `a`, checking that it maps vectors of the length of `b` to
+//vectors of the same length
+pub(super) fn mult_square<T, A>(a: &A, x: &[T]) -> Result<Vec<T>, MatrixError>
+where
+	T: Float,
+	A: LinearOperator<T> + ?Sized,
+{
+	let y = a.try_apply(x)?;
+	if y.len() != x.len() {
+		return Err(MatrixError::NotSquare {
+			rows: y.len(),
+			columns: x.len(),
+		});
+	}
+	Ok(y)
+}
+
+//the result of a solve that stops before iterating, for a zero or empty rhs
+pub(super) fn trivial_solution<T: Float>(b: &[T]) -> Result<Option<SolverResult<T>>, MatrixError> {
+	if b.is_empty() {
+		return Err(MatrixError::EmptyOperand);
+	}
+	if norm(b) == T::zero() {
+		return Ok(Some(SolverResult {
+			solution: vec![T::zero(); b.len()],
+			iterations: 0,
+			residual_history: vec![T::zero()],
+			converged: true,
+		}));
+	}
+	Ok(None)
+}
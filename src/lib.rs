@@ -1,6 +1,22 @@
 #[doc = include_str!("../README.md")]
+pub mod array_operations;
+pub mod decompositions;
+mod dynamic;
+mod error;
+mod fixed_size;
+pub mod iterative;
+mod linear_operator;
+mod quaternion;
+pub mod sparse;
+pub mod transformations;
 pub mod vector_operations;
-use std::ops::{Add, AddAssign, Mul, Sub};
+pub use dynamic::{DynMatrix, DynMatrixRef, DynVector, DynVectorRef};
+pub use error::MatrixError;
+pub use fixed_size::{Matrix, Vector};
+pub use linear_operator::FnOperator;
+use num::Float;
+pub use quaternion::Quaternion;
+use std::ops::{Add, AddAssign, IndexMut, Mul, Sub};
 
 #[cfg(target_pointer_width = "64")]
 pub type Fsize = f64;
@@ -25,38 +41,426 @@ pub const I4: Matrix3D = [
 
 pub const I3: Matrix2D = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
 
-pub trait VectorOps<Rhs, A> {
+pub trait VectorOps<Rhs: ?Sized, A> {
 	type Output;
-	fn vec_scal(&self, scal: A) -> Vec<Self::Output>;
-	fn vec_add(&self, rhs: &Rhs) -> Vec<Self::Output>;
-	fn dot(&self, rhs: &Rhs) -> Self::Output;
+	type VectorOutput;
+	fn try_vec_scal(&self, scal: A) -> Result<Self::VectorOutput, MatrixError>;
+	fn try_vec_add(&self, rhs: &Rhs) -> Result<Self::VectorOutput, MatrixError>;
+	fn try_dot(&self, rhs: &Rhs) -> Result<Self::Output, MatrixError>;
+	/// Cross product of two 3 element vectors, or of the X, Y and Z components
+	/// of two 4 element homogeneous vectors, in which case W of the result is
+	/// zero.
+	fn try_cross(&self, rhs: &Rhs) -> Result<Self::VectorOutput, MatrixError>;
+
+	fn vec_scal(&self, scal: A) -> Self::VectorOutput {
+		self.try_vec_scal(scal)
+			.unwrap_or_else(|err| panic!("{}", err))
+	}
+	fn vec_add(&self, rhs: &Rhs) -> Self::VectorOutput {
+		self.try_vec_add(rhs)
+			.unwrap_or_else(|err| panic!("{}", err))
+	}
+	fn dot(&self, rhs: &Rhs) -> Self::Output {
+		self.try_dot(rhs).unwrap_or_else(|err| panic!("{}", err))
+	}
+	fn cross(&self, rhs: &Rhs) -> Self::VectorOutput {
+		self.try_cross(rhs).unwrap_or_else(|err| panic!("{}", err))
+	}
 }
 
-pub trait VecScalingProjection<Rhs> {
+pub trait VecScalingProjection<Rhs: ?Sized> {
 	type Output;
-	fn magnitude(&self) -> Self::Output;
-	fn vec_scalar_components(&self, rhs: &Rhs) -> Self::Output;
-	fn vec_projection(&self, rhs: &Rhs) -> Vec<Self::Output>;
+	type VectorOutput;
+	fn try_magnitude(&self) -> Result<Self::Output, MatrixError>;
+	fn try_vec_scalar_components(&self, rhs: &Rhs) -> Result<Self::Output, MatrixError>;
+	fn try_vec_projection(&self, rhs: &Rhs) -> Result<Self::VectorOutput, MatrixError>;
+	fn try_normalize(&self) -> Result<Self::VectorOutput, MatrixError>;
+	fn try_distance(&self, rhs: &Rhs) -> Result<Self::Output, MatrixError>;
+	/// The angle between the two vectors in radians, from 0 to π.
+	fn try_angle(&self, rhs: &Rhs) -> Result<Self::Output, MatrixError>;
+
+	fn magnitude(&self) -> Self::Output {
+		self.try_magnitude().unwrap_or_else(|err| panic!("{}", err))
+	}
+	fn vec_scalar_components(&self, rhs: &Rhs) -> Self::Output {
+		self.try_vec_scalar_components(rhs)
+			.unwrap_or_else(|err| panic!("{}", err))
+	}
+	fn vec_projection(&self, rhs: &Rhs) -> Self::VectorOutput {
+		self.try_vec_projection(rhs)
+			.unwrap_or_else(|err| panic!("{}", err))
+	}
+	fn normalize(&self) -> Self::VectorOutput {
+		self.try_normalize().unwrap_or_else(|err| panic!("{}", err))
+	}
+	fn distance(&self, rhs: &Rhs) -> Self::Output {
+		self.try_distance(rhs)
+			.unwrap_or_else(|err| panic!("{}", err))
+	}
+	fn angle(&self, rhs: &Rhs) -> Self::Output {
+		self.try_angle(rhs).unwrap_or_else(|err| panic!("{}", err))
+	}
+}
+
+pub trait MatrixOperations<Rhs: ?Sized, Rhs2: ?Sized, Rhs3> {
+	type Output;
+	type MatrixOutput;
+	type VectorOutput;
+	fn try_matrix_add_matrix(&self, rhs: &Rhs) -> Result<Self::MatrixOutput, MatrixError>;
+	fn try_matrix_mult_matrix(&self, rhs: &Rhs) -> Result<Self::MatrixOutput, MatrixError>;
+	fn try_matrix_mult_point(&self, rhs: &Rhs2) -> Result<Self::VectorOutput, MatrixError>;
+	fn try_scaler_mult_matrix(&self, rhs: Rhs3) -> Result<Self::MatrixOutput, MatrixError>;
+	fn try_transpose(&self) -> Result<Self::MatrixOutput, MatrixError>;
+	fn try_trace(&self) -> Result<Self::Output, MatrixError>;
+	fn try_determinant(&self) -> Result<Self::Output, MatrixError>
+	where
+		Self::Output: Float;
+	/// Finds `x` such that `self·x = rhs`, in the least squares sense when
+	/// `self` has more rows than columns.
+	fn try_solve(&self, rhs: &Rhs2) -> Result<Self::VectorOutput, MatrixError>
+	where
+		Self::Output: Float;
+
+	fn matrix_add_matrix(&self, rhs: &Rhs) -> Self::MatrixOutput {
+		self.try_matrix_add_matrix(rhs)
+			.unwrap_or_else(|err| panic!("{}", err))
+	}
+	fn matrix_mult_matrix(&self, rhs: &Rhs) -> Self::MatrixOutput {
+		self.try_matrix_mult_matrix(rhs)
+			.unwrap_or_else(|err| panic!("{}", err))
+	}
+	fn matrix_mult_point(&self, rhs: &Rhs2) -> Self::VectorOutput {
+		self.try_matrix_mult_point(rhs)
+			.unwrap_or_else(|err| panic!("{}", err))
+	}
+	fn scaler_mult_matrix(&self, rhs: Rhs3) -> Self::MatrixOutput {
+		self.try_scaler_mult_matrix(rhs)
+			.unwrap_or_else(|err| panic!("{}", err))
+	}
+	fn transpose(&self) -> Self::MatrixOutput {
+		self.try_transpose().unwrap_or_else(|err| panic!("{}", err))
+	}
+	fn trace(&self) -> Self::Output {
+		self.try_trace().unwrap_or_else(|err| panic!("{}", err))
+	}
+	fn determinant(&self) -> Self::Output
+	where
+		Self::Output: Float,
+	{
+		self.try_determinant()
+			.unwrap_or_else(|err| panic!("{}", err))
+	}
+	fn solve(&self, rhs: &Rhs2) -> Self::VectorOutput
+	where
+		Self::Output: Float,
+	{
+		self.try_solve(rhs).unwrap_or_else(|err| panic!("{}", err))
+	}
 }
 
-pub trait MatrixOperations<Rhs, Rhs2, Rhs3> {
+pub trait MatrixInverse {
 	type Output;
-	fn matrix_add_matrix(&self, rhs: &Rhs) -> Vec<Vec<Self::Output>>;
-	fn matrix_mult_matrix(&self, rhs: &Rhs) -> Vec<Vec<Self::Output>>;
-	fn matrix_mult_point(&self, rhs: &Rhs2) -> Vec<Self::Output>;
-	fn scaler_mult_matrix(&self, rhs: Rhs3) -> Vec<Vec<Self::Output>>;
+	type MatrixOutput;
+	fn try_matrix_inverse(
+		&self,
+		tolerance: Self::Output,
+	) -> Result<Self::MatrixOutput, MatrixError>;
+
+	fn matrix_inverse(&self, tolerance: Self::Output) -> Self::MatrixOutput {
+		self.try_matrix_inverse(tolerance)
+			.unwrap_or_else(|err| panic!("{}", err))
+	}
 }
 
 pub trait TwoDimMatrixOps<Rhs> {
 	type Output;
-	fn matrix_two_dim_rotate(&self, wheta: Rhs, homogeneous_flag: bool) -> Vec<Self::Output>;
+	fn try_matrix_two_dim_rotate(
+		&self,
+		wheta: Rhs,
+		homogeneous_flag: bool,
+	) -> Result<Vec<Self::Output>, MatrixError>;
+
+	fn matrix_two_dim_rotate(&self, wheta: Rhs, homogeneous_flag: bool) -> Vec<Self::Output> {
+		self.try_matrix_two_dim_rotate(wheta, homogeneous_flag)
+			.unwrap_or_else(|err| panic!("{}", err))
+	}
+}
+
+/// A principal axis of three dimensional space.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+	X,
+	Y,
+	Z,
+}
+
+pub trait ThreeDimMatrixOps<Rhs> {
+	type Output;
+	fn try_matrix_three_dim_rotate(
+		&self,
+		axis: Axis,
+		wheta: Rhs,
+		homogeneous_flag: bool,
+	) -> Result<Vec<Self::Output>, MatrixError>;
+
+	fn matrix_three_dim_rotate(
+		&self,
+		axis: Axis,
+		wheta: Rhs,
+		homogeneous_flag: bool,
+	) -> Vec<Self::Output> {
+		self.try_matrix_three_dim_rotate(axis, wheta, homogeneous_flag)
+			.unwrap_or_else(|err| panic!("{}", err))
+	}
+}
+
+pub trait SparseOperations<Rhs: ?Sized, Rhs2: ?Sized> {
+	type VectorOutput;
+	type SparseOutput;
+	fn try_sparse_mult_vector(&self, rhs: &Rhs2) -> Result<Self::VectorOutput, MatrixError>;
+	fn try_sparse_mult_sparse(&self, rhs: &Rhs) -> Result<Self::SparseOutput, MatrixError>;
+	fn try_sparse_add_sparse(&self, rhs: &Rhs) -> Result<Self::SparseOutput, MatrixError>;
+	fn sparse_transpose(&self) -> Self::SparseOutput;
+
+	fn sparse_mult_vector(&self, rhs: &Rhs2) -> Self::VectorOutput {
+		self.try_sparse_mult_vector(rhs)
+			.unwrap_or_else(|err| panic!("{}", err))
+	}
+	fn sparse_mult_sparse(&self, rhs: &Rhs) -> Self::SparseOutput {
+		self.try_sparse_mult_sparse(rhs)
+			.unwrap_or_else(|err| panic!("{}", err))
+	}
+	fn sparse_add_sparse(&self, rhs: &Rhs) -> Self::SparseOutput {
+		self.try_sparse_add_sparse(rhs)
+			.unwrap_or_else(|err| panic!("{}", err))
+	}
+}
+
+/// A linear map applied to vectors without needing its matrix, such as a
+/// stencil, a convolution or a closure.
+pub trait LinearOperator<T> {
+	fn try_apply(&self, x: &[T]) -> Result<Vec<T>, MatrixError>;
+	/// Applies the transposed map. Operators that cannot, such as plain
+	/// closures, return `MatrixError::TransposeUnavailable`.
+	fn try_apply_transpose(&self, _x: &[T]) -> Result<Vec<T>, MatrixError> {
+		Err(MatrixError::TransposeUnavailable)
+	}
+
+	fn apply(&self, x: &[T]) -> Vec<T> {
+		self.try_apply(x).unwrap_or_else(|err| panic!("{}", err))
+	}
+	fn apply_transpose(&self, x: &[T]) -> Vec<T> {
+		self.try_apply_transpose(x)
+			.unwrap_or_else(|err| panic!("{}", err))
+	}
 }
 
-pub trait PointOperations<Rhs, Rhs2> {
+pub trait PointOperations<Rhs: ?Sized, Rhs2> {
 	type Output;
-	fn point_mult_scaler(&self, rhs: Rhs2) -> Vec<Self::Output>;
-	fn point_minus_point(&self, rhs: &Rhs) -> Vec<Self::Output>;
-	fn point_plus_vector(&self, rhs: &Rhs) -> Vec<Self::Output>;
+	type VectorOutput;
+	fn try_point_mult_scaler(&self, rhs: Rhs2) -> Result<Self::VectorOutput, MatrixError>;
+	fn try_point_minus_point(&self, rhs: &Rhs) -> Result<Self::VectorOutput, MatrixError>;
+	fn try_point_plus_vector(&self, rhs: &Rhs) -> Result<Self::VectorOutput, MatrixError>;
+
+	fn point_mult_scaler(&self, rhs: Rhs2) -> Self::VectorOutput {
+		self.try_point_mult_scaler(rhs)
+			.unwrap_or_else(|err| panic!("{}", err))
+	}
+	fn point_minus_point(&self, rhs: &Rhs) -> Self::VectorOutput {
+		self.try_point_minus_point(rhs)
+			.unwrap_or_else(|err| panic!("{}", err))
+	}
+	fn point_plus_vector(&self, rhs: &Rhs) -> Self::VectorOutput {
+		self.try_point_plus_vector(rhs)
+			.unwrap_or_else(|err| panic!("{}", err))
+	}
+}
+
+//checks that two vectors are non empty and of the same length
+pub(crate) fn check_same_length<T>(lhs: &[T], rhs: &[T]) -> Result<(), MatrixError> {
+	if lhs.is_empty() || rhs.is_empty() {
+		return Err(MatrixError::EmptyOperand);
+	} else if lhs.len() != rhs.len() {
+		return Err(MatrixError::DimensionMismatch {
+			lhs: (lhs.len(), 1),
+			rhs: (rhs.len(), 1),
+		});
+	}
+	Ok(())
+}
+
+//the operand of a matrix of `shape` must have `length` elements
+pub(crate) fn check_operand_length<T>(
+	shape: (usize, usize),
+	length: usize,
+	x: &[T],
+) -> Result<(), MatrixError> {
+	if x.is_empty() {
+		return Err(MatrixError::EmptyOperand);
+	} else if x.len() != length {
+		return Err(MatrixError::DimensionMismatch {
+			lhs: shape,
+			rhs: (x.len(), 1),
+		});
+	}
+	Ok(())
+}
+
+//returns the (rows, columns) shape of a non empty, non ragged matrix
+pub(crate) fn matrix_shape<T>(matrix: &[Vec<T>]) -> Result<(usize, usize), MatrixError> {
+	if matrix.is_empty() || matrix[0].is_empty() {
+		return Err(MatrixError::EmptyOperand);
+	}
+	let column_length = matrix[0].len();
+	for (row, vec_row) in matrix.iter().enumerate() {
+		if vec_row.len() != column_length {
+			return Err(MatrixError::RaggedRows {
+				row,
+				expected: column_length,
+				found: vec_row.len(),
+			});
+		}
+	}
+	Ok((matrix.len(), column_length))
+}
+
+//returns the size of a non empty, non ragged, square matrix
+pub(crate) fn square_size<T>(matrix: &[Vec<T>]) -> Result<usize, MatrixError> {
+	let (rows, columns) = matrix_shape(matrix)?;
+	if rows != columns {
+		return Err(MatrixError::NotSquare { rows, columns });
+	}
+	Ok(rows)
+}
+
+//returns the size of a square matrix whose mirrored entries agree within a
+//relative tolerance of sqrt(epsilon)
+#[allow(clippy::needless_range_loop)]
+pub(crate) fn symmetric_size<T: Float>(matrix: &[Vec<T>]) -> Result<usize, MatrixError> {
+	let size = square_size(matrix)?;
+	let tolerance = T::epsilon().sqrt();
+	for row in 0..size {
+		for column in row + 1..size {
+			let (upper, lower) = (matrix[row][column], matrix[column][row]);
+			if (upper - lower).abs() > tolerance * upper.abs().max(lower.abs()) {
+				return Err(MatrixError::NotSymmetric { row, column });
+			}
+		}
+	}
+	Ok(size)
+}
+
+//Gauss-Jordan elimination with partial pivoting, reducing `matrix` to the
+//identity while applying the same row operations to `inverse`, which must
+//start as the identity
+pub(crate) fn gauss_jordan_inverse<T, M>(
+	mut matrix: M,
+	mut inverse: M,
+	size: usize,
+	tolerance: T,
+) -> Result<M, MatrixError>
+where
+	T: Float,
+	M: IndexMut<usize>,
+	M::Output: IndexMut<usize, Output = T>,
+{
+	for column in 0..size {
+		let mut pivot_row = column;
+		for row in column + 1..size {
+			if matrix[row][column].abs() > matrix[pivot_row][column].abs() {
+				pivot_row = row;
+			}
+		}
+		if matrix[pivot_row][column].abs() <= tolerance {
+			return Err(MatrixError::Singular);
+		}
+		if pivot_row != column {
+			for index in 0..size {
+				let temp = matrix[column][index];
+				matrix[column][index] = matrix[pivot_row][index];
+				matrix[pivot_row][index] = temp;
+				let temp = inverse[column][index];
+				inverse[column][index] = inverse[pivot_row][index];
+				inverse[pivot_row][index] = temp;
+			}
+		}
+
+		let pivot = matrix[column][column];
+		for index in 0..size {
+			matrix[column][index] = matrix[column][index] / pivot;
+			inverse[column][index] = inverse[column][index] / pivot;
+		}
+		for row in 0..size {
+			let factor = matrix[row][column];
+			if row == column || factor == T::zero() {
+				continue;
+			}
+			for index in 0..size {
+				matrix[row][index] = matrix[row][index] - factor * matrix[column][index];
+				inverse[row][index] = inverse[row][index] - factor * inverse[column][index];
+			}
+		}
+	}
+	Ok(inverse)
+}
+
+//determinant of a square matrix, in closed form up to 4x4 and by LU
+//elimination with partial pivoting beyond
+pub(crate) fn square_determinant<T, M>(mut matrix: M, size: usize) -> T
+where
+	T: Float,
+	M: IndexMut<usize>,
+	M::Output: IndexMut<usize, Output = T>,
+{
+	let m = &matrix;
+	let det2 =
+		|r0: usize, r1: usize, c0: usize, c1: usize| m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0];
+	let det3 = |rows: [usize; 3], columns: [usize; 3]| {
+		m[rows[0]][columns[0]] * det2(rows[1], rows[2], columns[1], columns[2])
+			- m[rows[0]][columns[1]] * det2(rows[1], rows[2], columns[0], columns[2])
+			+ m[rows[0]][columns[2]] * det2(rows[1], rows[2], columns[0], columns[1])
+	};
+	match size {
+		1 => return m[0][0],
+		2 => return det2(0, 1, 0, 1),
+		3 => return det3([0, 1, 2], [0, 1, 2]),
+		4 => {
+			return m[0][0] * det3([1, 2, 3], [1, 2, 3]) - m[0][1] * det3([1, 2, 3], [0, 2, 3])
+				+ m[0][2] * det3([1, 2, 3], [0, 1, 3])
+				- m[0][3] * det3([1, 2, 3], [0, 1, 2])
+		}
+		_ => {}
+	}
+
+	let mut determinant = T::one();
+	for column in 0..size {
+		let mut pivot_row = column;
+		for row in column + 1..size {
+			if matrix[row][column].abs() > matrix[pivot_row][column].abs() {
+				pivot_row = row;
+			}
+		}
+		if matrix[pivot_row][column] == T::zero() {
+			return T::zero();
+		}
+		if pivot_row != column {
+			for index in column..size {
+				let temp = matrix[column][index];
+				matrix[column][index] = matrix[pivot_row][index];
+				matrix[pivot_row][index] = temp;
+			}
+			determinant = -determinant;
+		}
+		let pivot = matrix[column][column];
+		determinant = determinant * pivot;
+		for row in column + 1..size {
+			let factor = matrix[row][column] / pivot;
+			for index in column..size {
+				matrix[row][index] = matrix[row][index] - factor * matrix[column][index];
+			}
+		}
+	}
+	determinant
 }
 
 //for loop used to perform operations which only require one for loop
@@ -0,0 +1,79 @@
+use crate::{LinearOperator, MatrixError};
+
+/// A closure is a linear operator without a transpose.
+impl<T, F> LinearOperator<T> for F
+where
+	F: Fn(&[T]) -> Vec<T>,
+{
+	fn try_apply(&self, x: &[T]) -> Result<Vec<T>, MatrixError> {
+		Ok(self(x))
+	}
+}
+
+/// A matrix free operator built from a pair of closures, applying the map and
+/// its transpose.
+#[derive(Debug, Clone, Copy)]
+pub struct FnOperator<F, G> {
+	apply: F,
+	apply_transpose: G,
+}
+
+impl<F, G> FnOperator<F, G> {
+	pub fn new(apply: F, apply_transpose: G) -> Self {
+		FnOperator {
+			apply,
+			apply_transpose,
+		}
+	}
+}
+
+impl<T, F, G> LinearOperator<T> for FnOperator<F, G>
+where
+	F: Fn(&[T]) -> Vec<T>,
+	G: Fn(&[T]) -> Vec<T>,
+{
+	fn try_apply(&self, x: &[T]) -> Result<Vec<T>, MatrixError> {
+		Ok((self.apply)(x))
+	}
+	fn try_apply_transpose(&self, x: &[T]) -> Result<Vec<T>, MatrixError> {
+		Ok((self.apply_transpose)(x))
+	}
+}
+
+#[cfg(test)]
+mod test_linear_operator {
+	use super::FnOperator;
+	use crate::{Fsize, LinearOperator, MatrixError};
+
+	//the forward difference of a vector, zero past its end
+	fn difference(x: &[Fsize]) -> Vec<Fsize> {
+		(0..x.len())
+			.map(|index| x.get(index + 1).copied().unwrap_or(0.0) - x[index])
+			.collect()
+	}
+	//its transpose, the negated backward difference
+	fn difference_transpose(x: &[Fsize]) -> Vec<Fsize> {
+		(0..x.len())
+			.map(|index| if index == 0 { 0.0 } else { x[index - 1] } - x[index])
+			.collect()
+	}
+
+	#[test]
+	fn test_closure_operators() {
+		let x: Vec<Fsize> = vec![1.0, 4.0, 9.0];
+		let dense: Vec<Vec<Fsize>> = vec![
+			vec![-1.0, 1.0, 0.0],
+			vec![0.0, -1.0, 1.0],
+			vec![0.0, 0.0, -1.0],
+		];
+		let operator = FnOperator::new(difference, difference_transpose);
+
+		assert_eq!(dense.apply(&x), difference.apply(&x));
+		assert_eq!(
+			Err(MatrixError::TransposeUnavailable),
+			difference.try_apply_transpose(&x)
+		);
+		assert_eq!(dense.apply(&x), operator.apply(&x));
+		assert_eq!(dense.apply_transpose(&x), operator.apply_transpose(&x));
+	}
+}
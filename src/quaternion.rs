@@ -0,0 +1,274 @@
+use crate::{MatrixError, VecScalingProjection};
+use num::Float;
+use std::ops::{AddAssign, SubAssign};
+
+/// A quaternion `w + xi + yj + zk`.
+///
+/// Rotations are represented by unit quaternions; `rotate_point`, `rotate_vec`
+/// and `to_matrix` expect `self` to be normalized.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quaternion<T> {
+	pub w: T,
+	pub x: T,
+	pub y: T,
+	pub z: T,
+}
+
+impl<T> Quaternion<T>
+where
+	T: AddAssign + SubAssign + Float,
+{
+	pub fn new(w: T, x: T, y: T, z: T) -> Self {
+		Quaternion { w, x, y, z }
+	}
+	pub fn identity() -> Self {
+		Quaternion::new(T::one(), T::zero(), T::zero(), T::zero())
+	}
+
+	/// Builds the unit quaternion rotating `theta_rad` radians about `axis`.
+	pub fn from_axis_angle(axis: &[T; 3], theta_rad: T) -> Result<Self, MatrixError> {
+		let magnitude = axis.try_magnitude()?;
+		if magnitude == T::zero() {
+			return Err(MatrixError::ZeroMagnitude);
+		}
+		let half = theta_rad / (T::one() + T::one());
+		let scale = half.sin() / magnitude;
+		Ok(Quaternion::new(
+			half.cos(),
+			axis[0] * scale,
+			axis[1] * scale,
+			axis[2] * scale,
+		))
+	}
+
+	/// Hamilton product `self * rhs`, the rotation `rhs` followed by `self`.
+	pub fn mul(&self, rhs: &Quaternion<T>) -> Quaternion<T> {
+		Quaternion::new(
+			self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+			self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+			self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+			self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+		)
+	}
+	pub fn conjugate(&self) -> Quaternion<T> {
+		Quaternion::new(self.w, -self.x, -self.y, -self.z)
+	}
+	pub fn norm(&self) -> T {
+		(self.w * self.w + self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+	}
+	pub fn normalize(&self) -> Result<Quaternion<T>, MatrixError> {
+		let norm = self.norm();
+		if norm == T::zero() {
+			return Err(MatrixError::ZeroMagnitude);
+		}
+		Ok(Quaternion::new(
+			self.w / norm,
+			self.x / norm,
+			self.y / norm,
+			self.z / norm,
+		))
+	}
+	pub fn inverse(&self) -> Result<Quaternion<T>, MatrixError> {
+		let norm_squared = self.w * self.w + self.x * self.x + self.y * self.y + self.z * self.z;
+		if norm_squared == T::zero() {
+			return Err(MatrixError::ZeroMagnitude);
+		}
+		let conjugate = self.conjugate();
+		Ok(Quaternion::new(
+			conjugate.w / norm_squared,
+			conjugate.x / norm_squared,
+			conjugate.y / norm_squared,
+			conjugate.z / norm_squared,
+		))
+	}
+
+	/// Converts to a homogeneous rotation matrix, a `Matrix3D` when `T` is `Fsize`.
+	pub fn to_matrix(&self) -> [[T; 4]; 4] {
+		let (w, x, y, z) = (self.w, self.x, self.y, self.z);
+		let one = T::one();
+		let two = one + one;
+		let zero = T::zero();
+		[
+			[
+				one - two * (y * y + z * z),
+				two * (x * y - w * z),
+				two * (x * z + w * y),
+				zero,
+			],
+			[
+				two * (x * y + w * z),
+				one - two * (x * x + z * z),
+				two * (y * z - w * x),
+				zero,
+			],
+			[
+				two * (x * z - w * y),
+				two * (y * z + w * x),
+				one - two * (x * x + y * y),
+				zero,
+			],
+			[zero, zero, zero, one],
+		]
+	}
+
+	/// Converts the rotation held in the upper left 3x3 block of `matrix` to a
+	/// unit quaternion with a non negative `w`.
+	pub fn from_matrix(matrix: &[[T; 4]; 4]) -> Quaternion<T> {
+		let m = matrix;
+		let one = T::one();
+		let two = one + one;
+		let four = two + two;
+		let trace = m[0][0] + m[1][1] + m[2][2];
+
+		// branch on the largest component to avoid dividing by a small value
+		let quaternion = if trace > T::zero() {
+			let s = (trace + one).sqrt() * two;
+			Quaternion::new(
+				s / four,
+				(m[2][1] - m[1][2]) / s,
+				(m[0][2] - m[2][0]) / s,
+				(m[1][0] - m[0][1]) / s,
+			)
+		} else if m[0][0] > m[1][1] && m[0][0] > m[2][2] {
+			let s = (one + m[0][0] - m[1][1] - m[2][2]).sqrt() * two;
+			Quaternion::new(
+				(m[2][1] - m[1][2]) / s,
+				s / four,
+				(m[0][1] + m[1][0]) / s,
+				(m[0][2] + m[2][0]) / s,
+			)
+		} else if m[1][1] > m[2][2] {
+			let s = (one + m[1][1] - m[0][0] - m[2][2]).sqrt() * two;
+			Quaternion::new(
+				(m[0][2] - m[2][0]) / s,
+				(m[0][1] + m[1][0]) / s,
+				s / four,
+				(m[1][2] + m[2][1]) / s,
+			)
+		} else {
+			let s = (one + m[2][2] - m[0][0] - m[1][1]).sqrt() * two;
+			Quaternion::new(
+				(m[1][0] - m[0][1]) / s,
+				(m[0][2] + m[2][0]) / s,
+				(m[1][2] + m[2][1]) / s,
+				s / four,
+			)
+		};
+		if quaternion.w < T::zero() {
+			Quaternion::new(-quaternion.w, -quaternion.x, -quaternion.y, -quaternion.z)
+		} else {
+			quaternion
+		}
+	}
+
+	/// Rotates a homogeneous point, leaving its last component untouched.
+	pub fn rotate_point(&self, point: &[T; 4]) -> [T; 4] {
+		let [x, y, z] = self.rotate([point[0], point[1], point[2]]);
+		[x, y, z, point[3]]
+	}
+
+	/// Rotates a 3 element point, or a 4 element homogeneous point.
+	pub fn rotate_vec(&self, point: &[T]) -> Result<Vec<T>, MatrixError> {
+		if point.len() != 3 && point.len() != 4 {
+			return Err(MatrixError::DimensionMismatch {
+				lhs: (point.len(), 1),
+				rhs: (3, 1),
+			});
+		}
+		let mut rotated = self.rotate([point[0], point[1], point[2]]).to_vec();
+		if point.len() == 4 {
+			rotated.push(point[3]);
+		}
+		Ok(rotated)
+	}
+
+	//v' = v + 2w(q × v) + 2q × (q × v), where q is the vector part
+	fn rotate(&self, v: [T; 3]) -> [T; 3] {
+		let two = T::one() + T::one();
+		let cross = |a: [T; 3], b: [T; 3]| {
+			[
+				a[1] * b[2] - a[2] * b[1],
+				a[2] * b[0] - a[0] * b[2],
+				a[0] * b[1] - a[1] * b[0],
+			]
+		};
+		let q = [self.x, self.y, self.z];
+		let t = cross(q, v);
+		let u = cross(q, t);
+		[
+			v[0] + two * (self.w * t[0] + u[0]),
+			v[1] + two * (self.w * t[1] + u[1]),
+			v[2] + two * (self.w * t[2] + u[2]),
+		]
+	}
+}
+
+#[cfg(test)]
+mod test_quaternion {
+	use crate::transformations::{rotation_axis_angle, rotation_z};
+	use crate::{Fsize, MatrixError, MatrixOperations, Point3D, Quaternion};
+
+	fn assert_close(expected: &[Fsize], result: &[Fsize]) {
+		assert_eq!(expected.len(), result.len());
+		for index in 0..expected.len() {
+			assert!((expected[index] - result[index]).abs() < 1e-6);
+		}
+	}
+
+	#[test]
+	fn test_quaternion_product() {
+		let i = Quaternion::new(0.0, 1.0, 0.0, 0.0);
+		let j = Quaternion::new(0.0, 0.0, 1.0, 0.0);
+		let k = Quaternion::new(0.0, 0.0, 0.0, 1.0);
+		let q: Quaternion<Fsize> = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+
+		assert_eq!(k, i.mul(&j));
+		assert_eq!(Quaternion::new(-1.0, 0.0, 0.0, 0.0), k.mul(&k));
+		assert_eq!(Quaternion::new(30.0, 0.0, 0.0, 0.0), q.mul(&q.conjugate()));
+		let identity = q.mul(&q.inverse().unwrap());
+		assert_close(
+			&[1.0, 0.0, 0.0, 0.0],
+			&[identity.w, identity.x, identity.y, identity.z],
+		);
+		assert_eq!(
+			Err(MatrixError::ZeroMagnitude),
+			Quaternion::new(0.0, 0.0, 0.0, 0.0).normalize()
+		);
+		assert!((q.normalize().unwrap().norm() - 1.0).abs() < 1e-6);
+	}
+	#[test]
+	fn test_quaternion_matrix_conversion() {
+		let axis: [Fsize; 3] = [1.0, -2.0, 0.5];
+		for &theta in [0.3, 1.7, 3.0].iter() {
+			let q = Quaternion::from_axis_angle(&axis, theta).unwrap();
+			let matrix = rotation_axis_angle(&axis, theta).unwrap();
+			for (row, expected) in matrix.iter().enumerate() {
+				assert_close(expected, &q.to_matrix()[row]);
+			}
+			let back = Quaternion::from_matrix(&matrix);
+			assert_close(&[q.w, q.x, q.y, q.z], &[back.w, back.x, back.y, back.z]);
+		}
+	}
+	#[test]
+	fn test_quaternion_rotate() {
+		let half_pi = std::f64::consts::FRAC_PI_2 as Fsize;
+		let q = Quaternion::from_axis_angle(&[0.0, 0.0, 1.0], half_pi).unwrap();
+		let point: Point3D = [1.0, 2.0, 3.0, 1.0];
+		let point_vec: Vec<Fsize> = vec![1.0, 2.0, 3.0];
+
+		assert_close(
+			&rotation_z(half_pi).matrix_mult_point(&point),
+			&q.rotate_point(&point),
+		);
+		assert_close(&[-2.0, 1.0, 3.0], &q.rotate_vec(&point_vec).unwrap());
+		let composed = q.mul(&q);
+		assert_close(&[-1.0, -2.0, 3.0, 1.0], &composed.rotate_point(&point));
+		assert_eq!(
+			Err(MatrixError::DimensionMismatch {
+				lhs: (2, 1),
+				rhs: (3, 1)
+			}),
+			q.rotate_vec(&point_vec[..2])
+		);
+	}
+}
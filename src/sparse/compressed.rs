@@ -0,0 +1,223 @@
+use num::Zero;
+use std::ops::{Add, Mul};
+
+//compressed sparse storage shared by CSR, whose outer dimension is the rows,
+//and CSC, whose outer dimension is the columns. The non zeros of outer lane
+//`o` are `indices[offsets[o]..offsets[o + 1]]`, sorted, with their `values`.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct Compressed<T> {
+	pub(super) outer: usize,
+	pub(super) inner: usize,
+	pub(super) offsets: Vec<usize>,
+	pub(super) indices: Vec<usize>,
+	pub(super) values: Vec<T>,
+}
+
+impl<T> Compressed<T>
+where
+	T: Add<T, Output = T> + Mul<T, Output = T> + Zero + Copy,
+{
+	//builds the storage from (outer, inner, value) triplets, which must be in
+	//bounds, summing duplicates
+	pub(super) fn from_triplets(
+		outer: usize,
+		inner: usize,
+		mut triplets: Vec<(usize, usize, T)>,
+	) -> Self {
+		triplets.sort_by_key(|(outer_index, inner_index, _)| (*outer_index, *inner_index));
+		let mut offsets = vec![0; outer + 1];
+		let mut indices: Vec<usize> = Vec::with_capacity(triplets.len());
+		let mut values: Vec<T> = Vec::with_capacity(triplets.len());
+		let mut previous = None;
+		for (outer_index, inner_index, value) in triplets {
+			if previous == Some((outer_index, inner_index)) {
+				let last = values.len() - 1;
+				values[last] = values[last] + value;
+				continue;
+			}
+			previous = Some((outer_index, inner_index));
+			indices.push(inner_index);
+			values.push(value);
+			offsets[outer_index + 1] += 1;
+		}
+		for index in 0..outer {
+			offsets[index + 1] += offsets[index];
+		}
+		Compressed {
+			outer,
+			inner,
+			offsets,
+			indices,
+			values,
+		}
+	}
+
+	//stores the non zero entries of a dense, outer major, matrix
+	pub(super) fn from_dense(dense: &[Vec<T>], outer: usize, inner: usize) -> Self {
+		let triplets = dense
+			.iter()
+			.enumerate()
+			.flat_map(|(outer_index, lane)| {
+				lane.iter()
+					.enumerate()
+					.filter(|(_, value)| !value.is_zero())
+					.map(move |(inner_index, value)| (outer_index, inner_index, *value))
+			})
+			.collect();
+		Compressed::from_triplets(outer, inner, triplets)
+	}
+
+	pub(super) fn to_dense(&self) -> Vec<Vec<T>> {
+		let mut dense = vec![vec![T::zero(); self.inner]; self.outer];
+		for (outer_index, lane) in dense.iter_mut().enumerate() {
+			for (inner_index, value) in self.lane(outer_index) {
+				lane[inner_index] = value;
+			}
+		}
+		dense
+	}
+
+	pub(super) fn lane(&self, outer_index: usize) -> impl Iterator<Item = (usize, T)> + '_ {
+		let range = self.offsets[outer_index]..self.offsets[outer_index + 1];
+		self.indices[range.clone()]
+			.iter()
+			.copied()
+			.zip(self.values[range].iter().copied())
+	}
+
+	pub(super) fn get(&self, outer_index: usize, inner_index: usize) -> Option<T> {
+		let range = self.offsets[outer_index]..self.offsets[outer_index + 1];
+		self.indices[range.clone()]
+			.binary_search(&inner_index)
+			.ok()
+			.map(|position| self.values[range.start + position])
+	}
+
+	//the same matrix stored along the other dimension, which is also the
+	//transpose stored along the same dimension
+	pub(super) fn transpose(&self) -> Compressed<T> {
+		let mut offsets = vec![0; self.inner + 1];
+		for inner_index in self.indices.iter() {
+			offsets[inner_index + 1] += 1;
+		}
+		for index in 0..self.inner {
+			offsets[index + 1] += offsets[index];
+		}
+		// visiting the outer lanes in order keeps every new lane sorted
+		let mut next = offsets.clone();
+		let mut indices = vec![0; self.indices.len()];
+		let mut values = self.values.clone();
+		for outer_index in 0..self.outer {
+			for (inner_index, value) in self.lane(outer_index) {
+				let position = next[inner_index];
+				indices[position] = outer_index;
+				values[position] = value;
+				next[inner_index] += 1;
+			}
+		}
+		Compressed {
+			outer: self.inner,
+			inner: self.outer,
+			offsets,
+			indices,
+			values,
+		}
+	}
+
+	//y[o] = Σ value·x[i], with one entry per outer lane
+	pub(super) fn mult_vector_outer(&self, x: &[T]) -> Vec<T> {
+		(0..self.outer)
+			.map(|outer_index| {
+				self.lane(outer_index)
+					.fold(T::zero(), |sum, (inner_index, value)| {
+						sum + value * x[inner_index]
+					})
+			})
+			.collect()
+	}
+
+	//y[i] = Σ value·x[o], with one entry per inner index
+	pub(super) fn mult_vector_inner(&self, x: &[T]) -> Vec<T> {
+		let mut y = vec![T::zero(); self.inner];
+		for (outer_index, x_value) in x.iter().enumerate() {
+			for (inner_index, value) in self.lane(outer_index) {
+				y[inner_index] = y[inner_index] + value * *x_value;
+			}
+		}
+		y
+	}
+
+	//merges two matrices of the same shape and orientation
+	pub(super) fn add(&self, rhs: &Compressed<T>) -> Compressed<T> {
+		let mut offsets = Vec::with_capacity(self.outer + 1);
+		let mut indices = Vec::with_capacity(self.indices.len() + rhs.indices.len());
+		let mut values = Vec::with_capacity(indices.capacity());
+		offsets.push(0);
+		for outer_index in 0..self.outer {
+			let mut lhs_lane = self.lane(outer_index).peekable();
+			let mut rhs_lane = rhs.lane(outer_index).peekable();
+			loop {
+				let (inner_index, value) = match (lhs_lane.peek(), rhs_lane.peek()) {
+					(Some(lhs), Some(rhs)) if lhs.0 == rhs.0 => {
+						let merged = (lhs.0, lhs.1 + rhs.1);
+						lhs_lane.next();
+						rhs_lane.next();
+						merged
+					}
+					(Some(lhs), Some(rhs)) if lhs.0 < rhs.0 => lhs_lane.next().unwrap(),
+					(_, Some(_)) => rhs_lane.next().unwrap(),
+					(Some(_), None) => lhs_lane.next().unwrap(),
+					(None, None) => break,
+				};
+				indices.push(inner_index);
+				values.push(value);
+			}
+			offsets.push(indices.len());
+		}
+		Compressed {
+			outer: self.outer,
+			inner: self.inner,
+			offsets,
+			indices,
+			values,
+		}
+	}
+
+	//multiplies by a matrix whose outer dimension matches our inner one, one
+	//output lane at a time through a dense accumulator (Gustavson's algorithm)
+	pub(super) fn mult(&self, rhs: &Compressed<T>) -> Compressed<T> {
+		let mut offsets = Vec::with_capacity(self.outer + 1);
+		let mut indices = Vec::new();
+		let mut values = Vec::new();
+		let mut accumulator = vec![T::zero(); rhs.inner];
+		let mut occupied = vec![false; rhs.inner];
+		let mut touched = Vec::new();
+		offsets.push(0);
+		for outer_index in 0..self.outer {
+			for (middle, lhs_value) in self.lane(outer_index) {
+				for (inner_index, rhs_value) in rhs.lane(middle) {
+					if !occupied[inner_index] {
+						occupied[inner_index] = true;
+						touched.push(inner_index);
+					}
+					accumulator[inner_index] = accumulator[inner_index] + lhs_value * rhs_value;
+				}
+			}
+			touched.sort_unstable();
+			for inner_index in touched.drain(..) {
+				indices.push(inner_index);
+				values.push(accumulator[inner_index]);
+				accumulator[inner_index] = T::zero();
+				occupied[inner_index] = false;
+			}
+			offsets.push(indices.len());
+		}
+		Compressed {
+			outer: self.outer,
+			inner: rhs.inner,
+			offsets,
+			indices,
+			values,
+		}
+	}
+}
@@ -0,0 +1,112 @@
+use super::compressed::Compressed;
+use super::{CscMatrix, CsrMatrix};
+use crate::MatrixError;
+use num::Zero;
+use std::ops::{Add, Mul};
+
+/// A sparse matrix builder holding `(row, column, value)` entries in
+/// coordinate format.
+///
+/// Entries may be pushed in any order and the same position may be pushed
+/// more than once, as when assembling finite element matrices; duplicates are
+/// summed on conversion to CSR or CSC.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CooMatrix<T> {
+	rows: usize,
+	columns: usize,
+	entries: Vec<(usize, usize, T)>,
+}
+
+impl<T> CooMatrix<T>
+where
+	T: Add<T, Output = T> + Mul<T, Output = T> + Zero + Copy,
+{
+	pub fn new(rows: usize, columns: usize) -> Self {
+		CooMatrix {
+			rows,
+			columns,
+			entries: Vec::new(),
+		}
+	}
+	/// The `(rows, columns)` shape.
+	pub fn shape(&self) -> (usize, usize) {
+		(self.rows, self.columns)
+	}
+	pub fn entries(&self) -> &[(usize, usize, T)] {
+		&self.entries
+	}
+	pub fn try_push(&mut self, row: usize, column: usize, value: T) -> Result<(), MatrixError> {
+		if row >= self.rows || column >= self.columns {
+			return Err(MatrixError::IndexOutOfBounds {
+				row,
+				column,
+				shape: self.shape(),
+			});
+		}
+		self.entries.push((row, column, value));
+		Ok(())
+	}
+	pub fn push(&mut self, row: usize, column: usize, value: T) {
+		self.try_push(row, column, value)
+			.unwrap_or_else(|err| panic!("{}", err))
+	}
+	pub fn to_csr(&self) -> CsrMatrix<T> {
+		CsrMatrix(Compressed::from_triplets(
+			self.rows,
+			self.columns,
+			self.entries.clone(),
+		))
+	}
+	pub fn to_csc(&self) -> CscMatrix<T> {
+		let transposed = self
+			.entries
+			.iter()
+			.map(|(row, column, value)| (*column, *row, *value))
+			.collect();
+		CscMatrix(Compressed::from_triplets(
+			self.columns,
+			self.rows,
+			transposed,
+		))
+	}
+}
+
+#[cfg(test)]
+mod test_coo {
+	use super::CooMatrix;
+	use crate::{MatrixError, SparseOperations, VectorMD};
+
+	#[test]
+	fn test_coo_assembly() {
+		// two 1D elements sharing node 1 overlap on the diagonal
+		let mut stiffness = CooMatrix::new(3, 3);
+		for element in [0, 1].iter() {
+			stiffness.push(*element, *element, 1.0);
+			stiffness.push(*element, element + 1, -1.0);
+			stiffness.push(element + 1, *element, -1.0);
+			stiffness.push(element + 1, element + 1, 1.0);
+		}
+		let expected: VectorMD = vec![
+			vec![1.0, -1.0, 0.0],
+			vec![-1.0, 2.0, -1.0],
+			vec![0.0, -1.0, 1.0],
+		];
+
+		assert_eq!(8, stiffness.entries().len());
+		assert_eq!(7, stiffness.to_csr().nnz());
+		assert_eq!(expected, VectorMD::from(&stiffness.to_csr()));
+		assert_eq!(expected, VectorMD::from(&stiffness.to_csc()));
+		assert_eq!(
+			vec![-1.0, 0.0, 1.0],
+			stiffness.to_csr().sparse_mult_vector(&[0.0, 1.0, 2.0])
+		);
+		assert_eq!(
+			Err(MatrixError::IndexOutOfBounds {
+				row: 3,
+				column: 0,
+				shape: (3, 3)
+			}),
+			stiffness.try_push(3, 0, 1.0)
+		);
+	}
+}
@@ -0,0 +1,164 @@
+use super::compressed::Compressed;
+use super::CsrMatrix;
+use crate::{check_operand_length, matrix_shape, LinearOperator, MatrixError, SparseOperations};
+use num::Zero;
+use std::ops::{Add, Mul};
+
+/// A sparse matrix in compressed sparse column format, suited to column
+/// access and direct factorizations.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CscMatrix<T>(pub(super) Compressed<T>);
+
+impl<T> CscMatrix<T>
+where
+	T: Add<T, Output = T> + Mul<T, Output = T> + Zero + Copy,
+{
+	/// The `(rows, columns)` shape.
+	pub fn shape(&self) -> (usize, usize) {
+		(self.0.inner, self.0.outer)
+	}
+	/// The number of stored entries.
+	pub fn nnz(&self) -> usize {
+		self.0.values.len()
+	}
+	/// The entry at `row`, `column`, zero when it is not stored.
+	pub fn get(&self, row: usize, column: usize) -> T {
+		self.0.get(column, row).unwrap_or_else(T::zero)
+	}
+	/// The entries of column `c` are at
+	/// `column_offsets()[c]..column_offsets()[c + 1]` in `row_indices()` and
+	/// `values()`.
+	pub fn column_offsets(&self) -> &[usize] {
+		&self.0.offsets
+	}
+	pub fn row_indices(&self) -> &[usize] {
+		&self.0.indices
+	}
+	pub fn values(&self) -> &[T] {
+		&self.0.values
+	}
+	/// The entries on the main diagonal, zero where not stored.
+	pub fn diagonal(&self) -> Vec<T> {
+		let (rows, columns) = self.shape();
+		(0..rows.min(columns))
+			.map(|index| self.get(index, index))
+			.collect()
+	}
+	pub fn to_csr(&self) -> CsrMatrix<T> {
+		CsrMatrix(self.0.transpose())
+	}
+}
+
+impl<T> SparseOperations<CscMatrix<T>, [T]> for CscMatrix<T>
+where
+	T: Add<T, Output = T> + Mul<T, Output = T> + Zero + Copy,
+{
+	type VectorOutput = Vec<T>;
+	type SparseOutput = CscMatrix<T>;
+
+	fn try_sparse_mult_vector(&self, rhs: &[T]) -> Result<Vec<T>, MatrixError> {
+		check_operand_length(self.shape(), self.shape().1, rhs)?;
+		Ok(self.0.mult_vector_inner(rhs))
+	}
+	fn try_sparse_mult_sparse(&self, rhs: &CscMatrix<T>) -> Result<CscMatrix<T>, MatrixError> {
+		if self.shape().1 != rhs.shape().0 {
+			return Err(MatrixError::DimensionMismatch {
+				lhs: self.shape(),
+				rhs: rhs.shape(),
+			});
+		}
+		// column storage of A·B is row storage of Bᵀ·Aᵀ
+		Ok(CscMatrix(rhs.0.mult(&self.0)))
+	}
+	fn try_sparse_add_sparse(&self, rhs: &CscMatrix<T>) -> Result<CscMatrix<T>, MatrixError> {
+		if self.shape() != rhs.shape() {
+			return Err(MatrixError::DimensionMismatch {
+				lhs: self.shape(),
+				rhs: rhs.shape(),
+			});
+		}
+		Ok(CscMatrix(self.0.add(&rhs.0)))
+	}
+	fn sparse_transpose(&self) -> CscMatrix<T> {
+		CscMatrix(self.0.transpose())
+	}
+}
+
+impl<T> LinearOperator<T> for CscMatrix<T>
+where
+	T: Add<T, Output = T> + Mul<T, Output = T> + Zero + Copy,
+{
+	fn try_apply(&self, x: &[T]) -> Result<Vec<T>, MatrixError> {
+		self.try_sparse_mult_vector(x)
+	}
+	fn try_apply_transpose(&self, x: &[T]) -> Result<Vec<T>, MatrixError> {
+		check_operand_length(self.shape(), self.shape().0, x)?;
+		Ok(self.0.mult_vector_outer(x))
+	}
+}
+
+impl<T> TryFrom<&Vec<Vec<T>>> for CscMatrix<T>
+where
+	T: Add<T, Output = T> + Mul<T, Output = T> + Zero + Copy,
+{
+	type Error = MatrixError;
+
+	fn try_from(matrix: &Vec<Vec<T>>) -> Result<Self, MatrixError> {
+		let (rows, columns) = matrix_shape(matrix)?;
+		Ok(CscMatrix(
+			Compressed::from_dense(matrix, rows, columns).transpose(),
+		))
+	}
+}
+
+impl<T> From<&CscMatrix<T>> for Vec<Vec<T>>
+where
+	T: Add<T, Output = T> + Mul<T, Output = T> + Zero + Copy,
+{
+	fn from(matrix: &CscMatrix<T>) -> Self {
+		matrix.0.transpose().to_dense()
+	}
+}
+
+#[cfg(test)]
+mod test_csc {
+	use super::CscMatrix;
+	use crate::{MatrixOperations, SparseOperations, VectorMD};
+
+	#[test]
+	fn test_csc_operations() {
+		let dense_a: VectorMD = vec![
+			vec![1.0, 0.0, 2.0, 0.0],
+			vec![0.0, 0.0, 3.0, 0.0],
+			vec![4.0, 5.0, 0.0, 6.0],
+		];
+		let dense_b: VectorMD = vec![
+			vec![0.0, 1.0],
+			vec![2.0, 0.0],
+			vec![0.0, 0.0],
+			vec![1.0, 3.0],
+		];
+		let a = CscMatrix::try_from(&dense_a).unwrap();
+		let b = CscMatrix::try_from(&dense_b).unwrap();
+		let vector = vec![1.0, -1.0, 2.0, 0.5];
+
+		assert_eq!(&[0, 2, 3, 5, 6], a.column_offsets());
+		assert_eq!(&[0, 2, 2, 0, 1, 2], a.row_indices());
+		assert_eq!(2.0, a.get(0, 2));
+		assert_eq!(dense_a, VectorMD::from(&a));
+		assert_eq!(dense_a, VectorMD::from(&a.to_csr()));
+		assert_eq!(
+			dense_a.matrix_mult_point(&vector),
+			a.sparse_mult_vector(&vector)
+		);
+		assert_eq!(
+			dense_a.matrix_mult_matrix(&dense_b),
+			VectorMD::from(&a.sparse_mult_sparse(&b))
+		);
+		assert_eq!(dense_a.transpose(), VectorMD::from(&a.sparse_transpose()));
+		assert_eq!(
+			dense_a.scaler_mult_matrix(2.0),
+			VectorMD::from(&a.sparse_add_sparse(&a))
+		);
+	}
+}
@@ -0,0 +1,208 @@
+use super::compressed::Compressed;
+use super::CscMatrix;
+use crate::{check_operand_length, matrix_shape, LinearOperator, MatrixError, SparseOperations};
+use num::Zero;
+use std::ops::{Add, Mul};
+
+/// A sparse matrix in compressed sparse row format, suited to row access and
+/// matrix vector products.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsrMatrix<T>(pub(super) Compressed<T>);
+
+impl<T> CsrMatrix<T>
+where
+	T: Add<T, Output = T> + Mul<T, Output = T> + Zero + Copy,
+{
+	/// The `(rows, columns)` shape.
+	pub fn shape(&self) -> (usize, usize) {
+		(self.0.outer, self.0.inner)
+	}
+	/// The number of stored entries.
+	pub fn nnz(&self) -> usize {
+		self.0.values.len()
+	}
+	/// The entry at `row`, `column`, zero when it is not stored.
+	pub fn get(&self, row: usize, column: usize) -> T {
+		self.0.get(row, column).unwrap_or_else(T::zero)
+	}
+	/// The entries of row `r` are at `row_offsets()[r]..row_offsets()[r + 1]`
+	/// in `column_indices()` and `values()`.
+	pub fn row_offsets(&self) -> &[usize] {
+		&self.0.offsets
+	}
+	pub fn column_indices(&self) -> &[usize] {
+		&self.0.indices
+	}
+	pub fn values(&self) -> &[T] {
+		&self.0.values
+	}
+	/// The entries on the main diagonal, zero where not stored.
+	pub fn diagonal(&self) -> Vec<T> {
+		let (rows, columns) = self.shape();
+		(0..rows.min(columns))
+			.map(|index| self.get(index, index))
+			.collect()
+	}
+	pub fn to_csc(&self) -> CscMatrix<T> {
+		CscMatrix(self.0.transpose())
+	}
+}
+
+impl<T> SparseOperations<CsrMatrix<T>, [T]> for CsrMatrix<T>
+where
+	T: Add<T, Output = T> + Mul<T, Output = T> + Zero + Copy,
+{
+	type VectorOutput = Vec<T>;
+	type SparseOutput = CsrMatrix<T>;
+
+	fn try_sparse_mult_vector(&self, rhs: &[T]) -> Result<Vec<T>, MatrixError> {
+		check_operand_length(self.shape(), self.shape().1, rhs)?;
+		Ok(self.0.mult_vector_outer(rhs))
+	}
+	fn try_sparse_mult_sparse(&self, rhs: &CsrMatrix<T>) -> Result<CsrMatrix<T>, MatrixError> {
+		if self.shape().1 != rhs.shape().0 {
+			return Err(MatrixError::DimensionMismatch {
+				lhs: self.shape(),
+				rhs: rhs.shape(),
+			});
+		}
+		Ok(CsrMatrix(self.0.mult(&rhs.0)))
+	}
+	fn try_sparse_add_sparse(&self, rhs: &CsrMatrix<T>) -> Result<CsrMatrix<T>, MatrixError> {
+		if self.shape() != rhs.shape() {
+			return Err(MatrixError::DimensionMismatch {
+				lhs: self.shape(),
+				rhs: rhs.shape(),
+			});
+		}
+		Ok(CsrMatrix(self.0.add(&rhs.0)))
+	}
+	fn sparse_transpose(&self) -> CsrMatrix<T> {
+		CsrMatrix(self.0.transpose())
+	}
+}
+
+impl<T> LinearOperator<T> for CsrMatrix<T>
+where
+	T: Add<T, Output = T> + Mul<T, Output = T> + Zero + Copy,
+{
+	fn try_apply(&self, x: &[T]) -> Result<Vec<T>, MatrixError> {
+		self.try_sparse_mult_vector(x)
+	}
+	fn try_apply_transpose(&self, x: &[T]) -> Result<Vec<T>, MatrixError> {
+		check_operand_length(self.shape(), self.shape().0, x)?;
+		Ok(self.0.mult_vector_inner(x))
+	}
+}
+
+impl<T> TryFrom<&Vec<Vec<T>>> for CsrMatrix<T>
+where
+	T: Add<T, Output = T> + Mul<T, Output = T> + Zero + Copy,
+{
+	type Error = MatrixError;
+
+	fn try_from(matrix: &Vec<Vec<T>>) -> Result<Self, MatrixError> {
+		let (rows, columns) = matrix_shape(matrix)?;
+		Ok(CsrMatrix(Compressed::from_dense(matrix, rows, columns)))
+	}
+}
+
+impl<T> From<&CsrMatrix<T>> for Vec<Vec<T>>
+where
+	T: Add<T, Output = T> + Mul<T, Output = T> + Zero + Copy,
+{
+	fn from(matrix: &CsrMatrix<T>) -> Self {
+		matrix.0.to_dense()
+	}
+}
+
+#[cfg(test)]
+mod test_csr {
+	use super::CsrMatrix;
+	use crate::{MatrixError, MatrixOperations, SparseOperations, VectorMD};
+
+	fn dense_a() -> VectorMD {
+		vec![
+			vec![1.0, 0.0, 2.0, 0.0],
+			vec![0.0, 0.0, 3.0, 0.0],
+			vec![4.0, 5.0, 0.0, 6.0],
+		]
+	}
+	fn dense_b() -> VectorMD {
+		vec![
+			vec![0.0, 1.0, 0.0],
+			vec![2.0, 0.0, 0.0],
+			vec![0.0, 0.0, -1.0],
+			vec![1.0, 0.0, 3.0],
+		]
+	}
+
+	#[test]
+	fn test_csr_storage() {
+		let matrix = CsrMatrix::try_from(&dense_a()).unwrap();
+
+		assert_eq!((3, 4), matrix.shape());
+		assert_eq!(6, matrix.nnz());
+		assert_eq!(&[0, 2, 3, 6], matrix.row_offsets());
+		assert_eq!(&[0, 2, 2, 0, 1, 3], matrix.column_indices());
+		assert_eq!(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], matrix.values());
+		assert_eq!(5.0, matrix.get(2, 1));
+		assert_eq!(0.0, matrix.get(1, 1));
+		assert_eq!(dense_a(), VectorMD::from(&matrix));
+		assert_eq!(dense_a(), VectorMD::from(&matrix.to_csc()));
+	}
+	#[test]
+	fn test_csr_operations() {
+		let a = CsrMatrix::try_from(&dense_a()).unwrap();
+		let b = CsrMatrix::try_from(&dense_b()).unwrap();
+		let vector = vec![1.0, -1.0, 2.0, 0.5];
+
+		assert_eq!(
+			dense_a().matrix_mult_point(&vector),
+			a.sparse_mult_vector(&vector)
+		);
+		assert_eq!(
+			dense_a().matrix_mult_matrix(&dense_b()),
+			VectorMD::from(&a.sparse_mult_sparse(&b))
+		);
+		assert_eq!(dense_a().transpose(), VectorMD::from(&a.sparse_transpose()));
+		assert_eq!(
+			dense_a().matrix_add_matrix(&dense_a()),
+			VectorMD::from(&a.sparse_add_sparse(&a))
+		);
+		assert_eq!(
+			dense_b().transpose().matrix_add_matrix(&dense_a()),
+			VectorMD::from(&b.sparse_transpose().sparse_add_sparse(&a))
+		);
+	}
+	#[test]
+	fn test_csr_errors() {
+		let a = CsrMatrix::try_from(&dense_a()).unwrap();
+
+		assert_eq!(
+			Err(MatrixError::DimensionMismatch {
+				lhs: (3, 4),
+				rhs: (3, 4)
+			}),
+			a.try_sparse_mult_sparse(&a)
+		);
+		assert_eq!(
+			Err(MatrixError::DimensionMismatch {
+				lhs: (3, 4),
+				rhs: (3, 1)
+			}),
+			a.try_sparse_mult_vector(&[1.0, 2.0, 3.0])
+		);
+		assert_eq!(
+			Err(MatrixError::DimensionMismatch {
+				lhs: (3, 4),
+				rhs: (4, 3)
+			}),
+			a.try_sparse_add_sparse(&a.sparse_transpose())
+		);
+		assert_eq!(
+			Err(MatrixError::EmptyOperand),
+			CsrMatrix::<f64>::try_from(&vec![])
+		);
+	}
+}
@@ -0,0 +1,8 @@
+mod compressed;
+mod coo;
+mod csc;
+mod csr;
+
+pub use coo::CooMatrix;
+pub use csc::CscMatrix;
+pub use csr::CsrMatrix;
@@ -0,0 +1,393 @@
+use crate::{Fsize, Matrix2D, Matrix3D, MatrixError, VecScalingProjection};
+
+/// Builds a homogeneous matrix translating 2D points by `(tx, ty)`.
+pub fn translation_2d(tx: Fsize, ty: Fsize) -> Matrix2D {
+	[[1.0, 0.0, tx], [0.0, 1.0, ty], [0.0, 0.0, 1.0]]
+}
+
+/// Builds a homogeneous matrix translating 3D points by `(tx, ty, tz)`.
+pub fn translation_3d(tx: Fsize, ty: Fsize, tz: Fsize) -> Matrix3D {
+	[
+		[1.0, 0.0, 0.0, tx],
+		[0.0, 1.0, 0.0, ty],
+		[0.0, 0.0, 1.0, tz],
+		[0.0, 0.0, 0.0, 1.0],
+	]
+}
+
+/// Builds a homogeneous matrix scaling 2D points by `sx` and `sy` about the origin.
+pub fn scale_2d(sx: Fsize, sy: Fsize) -> Matrix2D {
+	[[sx, 0.0, 0.0], [0.0, sy, 0.0], [0.0, 0.0, 1.0]]
+}
+
+/// Builds a homogeneous matrix scaling 3D points by `sx`, `sy` and `sz` about the origin.
+pub fn scale_3d(sx: Fsize, sy: Fsize, sz: Fsize) -> Matrix3D {
+	[
+		[sx, 0.0, 0.0, 0.0],
+		[0.0, sy, 0.0, 0.0],
+		[0.0, 0.0, sz, 0.0],
+		[0.0, 0.0, 0.0, 1.0],
+	]
+}
+
+/// Builds a homogeneous 2D shear, mapping `(x, y)` to `(x + xy·y, y + yx·x)`.
+pub fn shear_2d(xy: Fsize, yx: Fsize) -> Matrix2D {
+	[[1.0, xy, 0.0], [yx, 1.0, 0.0], [0.0, 0.0, 1.0]]
+}
+
+/// Builds a homogeneous 3D shear. Each factor is named after the coordinate it
+/// changes followed by the coordinate it is proportional to, so `x` becomes
+/// `x + xy·y + xz·z`.
+pub fn shear_3d(xy: Fsize, xz: Fsize, yx: Fsize, yz: Fsize, zx: Fsize, zy: Fsize) -> Matrix3D {
+	[
+		[1.0, xy, xz, 0.0],
+		[yx, 1.0, yz, 0.0],
+		[zx, zy, 1.0, 0.0],
+		[0.0, 0.0, 0.0, 1.0],
+	]
+}
+
+/// Builds a homogeneous matrix reflecting 2D points across the line through
+/// the origin perpendicular to `normal`.
+pub fn reflection_2d(normal: &[Fsize; 2]) -> Result<Matrix2D, MatrixError> {
+	let [x, y] = unit_normal(normal)?;
+	Ok([
+		[1.0 - 2.0 * x * x, -2.0 * x * y, 0.0],
+		[-2.0 * y * x, 1.0 - 2.0 * y * y, 0.0],
+		[0.0, 0.0, 1.0],
+	])
+}
+
+/// Builds a homogeneous matrix reflecting 3D points across the plane through
+/// the origin perpendicular to `normal`.
+pub fn reflection_3d(normal: &[Fsize; 3]) -> Result<Matrix3D, MatrixError> {
+	let [x, y, z] = unit_normal(normal)?;
+	Ok([
+		[1.0 - 2.0 * x * x, -2.0 * x * y, -2.0 * x * z, 0.0],
+		[-2.0 * y * x, 1.0 - 2.0 * y * y, -2.0 * y * z, 0.0],
+		[-2.0 * z * x, -2.0 * z * y, 1.0 - 2.0 * z * z, 0.0],
+		[0.0, 0.0, 0.0, 1.0],
+	])
+}
+
+/// Inverts a homogeneous 2D affine transform from its 2x2 linear block and
+/// translation, which is faster and more accurate than a general inverse.
+pub fn affine_inverse_2d(matrix: &Matrix2D) -> Result<Matrix2D, MatrixError> {
+	check_homogeneous(matrix)?;
+	let m = matrix;
+	let determinant = m[0][0] * m[1][1] - m[0][1] * m[1][0];
+	check_determinant(matrix, determinant)?;
+	let mut inverse: Matrix2D = [
+		[m[1][1] / determinant, -m[0][1] / determinant, 0.0],
+		[-m[1][0] / determinant, m[0][0] / determinant, 0.0],
+		[0.0, 0.0, 1.0],
+	];
+	invert_translation(&mut inverse, matrix);
+	Ok(inverse)
+}
+
+/// Inverts a homogeneous 3D affine transform from its 3x3 linear block and
+/// translation, which is faster and more accurate than a general inverse.
+pub fn affine_inverse_3d(matrix: &Matrix3D) -> Result<Matrix3D, MatrixError> {
+	check_homogeneous(matrix)?;
+	let m = matrix;
+	// cofactors of the linear block, which form the transposed adjugate
+	let cofactor = |row: usize, column: usize| {
+		let (r0, r1) = ((row + 1) % 3, (row + 2) % 3);
+		let (c0, c1) = ((column + 1) % 3, (column + 2) % 3);
+		m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0]
+	};
+	let determinant =
+		m[0][0] * cofactor(0, 0) + m[0][1] * cofactor(0, 1) + m[0][2] * cofactor(0, 2);
+	check_determinant(matrix, determinant)?;
+	let mut inverse: Matrix3D = [[0.0; 4]; 4];
+	for (row, inverse_row) in inverse.iter_mut().take(3).enumerate() {
+		for (column, value) in inverse_row.iter_mut().take(3).enumerate() {
+			*value = cofactor(column, row) / determinant;
+		}
+	}
+	inverse[3][3] = 1.0;
+	invert_translation(&mut inverse, matrix);
+	Ok(inverse)
+}
+
+/// Inverts a homogeneous 2D rigid transform, a rotation followed by a
+/// translation, by transposing the rotation. The rotation is assumed to be
+/// orthonormal.
+pub fn rigid_inverse_2d(matrix: &Matrix2D) -> Result<Matrix2D, MatrixError> {
+	rigid_inverse(matrix)
+}
+
+/// Inverts a homogeneous 3D rigid transform, a rotation followed by a
+/// translation, by transposing the rotation. The rotation is assumed to be
+/// orthonormal.
+pub fn rigid_inverse_3d(matrix: &Matrix3D) -> Result<Matrix3D, MatrixError> {
+	rigid_inverse(matrix)
+}
+
+fn rigid_inverse<const N: usize>(matrix: &[[Fsize; N]; N]) -> Result<[[Fsize; N]; N], MatrixError> {
+	check_homogeneous(matrix)?;
+	let mut inverse: [[Fsize; N]; N] = std::array::from_fn(|row| {
+		std::array::from_fn(|column| {
+			if row < N - 1 && column < N - 1 {
+				matrix[column][row]
+			} else {
+				0.0
+			}
+		})
+	});
+	inverse[N - 1][N - 1] = 1.0;
+	invert_translation(&mut inverse, matrix);
+	Ok(inverse)
+}
+
+//fills the translation column of `inverse`, which already holds the inverted
+//linear block, with -L⁻¹·t where t is the translation of `matrix`
+fn invert_translation<const N: usize>(inverse: &mut [[Fsize; N]; N], matrix: &[[Fsize; N]; N]) {
+	for inverse_row in inverse.iter_mut().take(N - 1) {
+		let mut sum = 0.0;
+		for column in 0..N - 1 {
+			sum += inverse_row[column] * matrix[column][N - 1];
+		}
+		inverse_row[N - 1] = -sum;
+	}
+}
+
+fn check_homogeneous<const N: usize>(matrix: &[[Fsize; N]; N]) -> Result<(), MatrixError> {
+	let last_row = &matrix[N - 1];
+	let is_homogeneous = last_row
+		.iter()
+		.enumerate()
+		.all(|(column, value)| *value == if column == N - 1 { 1.0 } else { 0.0 });
+	if !is_homogeneous {
+		return Err(MatrixError::NonHomogeneous);
+	}
+	Ok(())
+}
+
+//by Hadamard's bound |det| is at most the product of the column norms of the
+//linear block, which follows the scale of each axis separately
+fn check_determinant<const N: usize>(
+	matrix: &[[Fsize; N]; N],
+	determinant: Fsize,
+) -> Result<(), MatrixError> {
+	let size = N - 1;
+	let column_norms = (0..size)
+		.map(|column| {
+			matrix[..size]
+				.iter()
+				.fold(0.0, |sum: Fsize, row| sum + row[column] * row[column])
+				.sqrt()
+		})
+		.product::<Fsize>();
+	if determinant.abs() <= column_norms * Fsize::EPSILON * size as Fsize {
+		return Err(MatrixError::Singular);
+	}
+	Ok(())
+}
+
+fn unit_normal<const N: usize>(normal: &[Fsize; N]) -> Result<[Fsize; N], MatrixError> {
+	let magnitude = normal.try_magnitude()?;
+	if magnitude == 0.0 {
+		return Err(MatrixError::ZeroMagnitude);
+	}
+	Ok(std::array::from_fn(|index| normal[index] / magnitude))
+}
+
+#[cfg(test)]
+mod test_affine {
+	use super::{
+		affine_inverse_2d, affine_inverse_3d, reflection_2d, reflection_3d, rigid_inverse_2d,
+		rigid_inverse_3d, scale_2d, scale_3d, shear_2d, shear_3d, translation_2d, translation_3d,
+	};
+	use crate::transformations::{rotation_axis_angle, rotation_z};
+	use crate::{
+		Matrix2D, Matrix3D, MatrixError, MatrixInverse, MatrixOperations, Point2D, Point3D, I3,
+	};
+
+	#[test]
+	fn test_translation_scale() {
+		let point_2d: Point2D = [1.0, 2.0, 1.0];
+		let point_3d: Point3D = [1.0, 2.0, 3.0, 1.0];
+
+		assert_eq!(
+			[4.0, 6.0, 1.0],
+			translation_2d(3.0, 4.0).matrix_mult_point(&point_2d)
+		);
+		assert_eq!(
+			[2.0, 0.0, 3.5, 1.0],
+			translation_3d(1.0, -2.0, 0.5).matrix_mult_point(&point_3d)
+		);
+		assert_eq!(
+			[2.0, -6.0, 1.0],
+			scale_2d(2.0, -3.0).matrix_mult_point(&point_2d)
+		);
+		assert_eq!(
+			[0.5, 4.0, 9.0, 1.0],
+			scale_3d(0.5, 2.0, 3.0).matrix_mult_point(&point_3d)
+		);
+	}
+	#[test]
+	fn test_shear() {
+		let point_2d: Point2D = [1.0, 2.0, 1.0];
+		let point_3d: Point3D = [1.0, 2.0, 3.0, 1.0];
+
+		assert_eq!(
+			[5.0, 1.5, 1.0],
+			shear_2d(2.0, -0.5).matrix_mult_point(&point_2d)
+		);
+		assert_eq!(
+			[
+				1.0 + 2.0 * 2.0 + 3.0 * 3.0,
+				2.0 + 1.0 * 1.0,
+				3.0 + 2.0 * 0.5,
+				1.0
+			],
+			shear_3d(2.0, 3.0, 1.0, 0.0, 0.0, 0.5).matrix_mult_point(&point_3d)
+		);
+	}
+	#[test]
+	fn test_reflection() {
+		let point_2d: Point2D = [1.0, 2.0, 1.0];
+		let point_3d: Point3D = [1.0, 2.0, 3.0, 1.0];
+
+		assert_eq!(
+			[1.0, -2.0, 1.0],
+			reflection_2d(&[0.0, 3.0])
+				.unwrap()
+				.matrix_mult_point(&point_2d)
+		);
+		assert_eq!(
+			[-1.0, 2.0, 3.0, 1.0],
+			reflection_3d(&[2.0, 0.0, 0.0])
+				.unwrap()
+				.matrix_mult_point(&point_3d)
+		);
+		let diagonal = reflection_2d(&[1.0, -1.0])
+			.unwrap()
+			.matrix_mult_point(&point_2d);
+		assert!((diagonal[0] - 2.0).abs() < 1e-6 && (diagonal[1] - 1.0).abs() < 1e-6);
+		assert_eq!(Err(MatrixError::ZeroMagnitude), reflection_2d(&[0.0, 0.0]));
+	}
+	#[test]
+	fn test_composition() {
+		// scale about the point (1, 1) by moving it to the origin and back
+		let about_point = translation_2d(1.0, 1.0)
+			.matrix_mult_matrix(&scale_2d(2.0, 2.0))
+			.matrix_mult_matrix(&translation_2d(-1.0, -1.0));
+		assert_eq!(
+			[[2.0, 0.0, -1.0], [0.0, 2.0, -1.0], [0.0, 0.0, 1.0]],
+			about_point
+		);
+		assert_eq!(
+			[1.0, 1.0, 1.0],
+			about_point.matrix_mult_point(&[1.0, 1.0, 1.0])
+		);
+
+		let transform = translation_3d(1.0, 2.0, 3.0).matrix_mult_matrix(&scale_3d(2.0, 2.0, 2.0));
+		assert_eq!(
+			[3.0, 4.0, 5.0, 1.0],
+			transform.matrix_mult_point(&[1.0, 1.0, 1.0, 1.0])
+		);
+	}
+	#[test]
+	fn test_affine_inverse() {
+		let transform_3d = translation_3d(1.0, -2.0, 3.0)
+			.matrix_mult_matrix(&rotation_axis_angle(&[1.0, 2.0, 3.0], 0.8).unwrap())
+			.matrix_mult_matrix(&shear_3d(0.5, 0.0, 0.0, 1.0, 0.2, 0.0))
+			.matrix_mult_matrix(&scale_3d(2.0, 3.0, 0.5));
+		let transform_2d = translation_2d(4.0, 5.0)
+			.matrix_mult_matrix(&shear_2d(1.5, 0.0))
+			.matrix_mult_matrix(&scale_2d(2.0, -1.0));
+
+		let general_3d = transform_3d.matrix_inverse(1e-12);
+		let affine_3d = affine_inverse_3d(&transform_3d).unwrap();
+		for row in 0..4 {
+			for column in 0..4 {
+				assert!((general_3d[row][column] - affine_3d[row][column]).abs() < 1e-9);
+			}
+		}
+		let product_2d = affine_inverse_2d(&transform_2d)
+			.unwrap()
+			.matrix_mult_matrix(&transform_2d);
+		for row in 0..3 {
+			for column in 0..3 {
+				assert!((product_2d[row][column] - I3[row][column]).abs() < 1e-9);
+			}
+		}
+		assert_eq!(
+			Err(MatrixError::Singular),
+			affine_inverse_3d(&scale_3d(1.0, 0.0, 1.0))
+		);
+		assert_eq!(
+			Err(MatrixError::Singular),
+			affine_inverse_2d(&scale_2d(0.0, 1.0))
+		);
+		// singular up to rounding, which leaves determinants around 1e-16
+		let nearly_singular: Matrix2D = [[0.1, 0.3, 0.0], [0.7, 2.1, 0.0], [0.0, 0.0, 1.0]];
+		let nearly_singular_3d: Matrix3D = [
+			[1.0, 2.0, 3.0, 1.0],
+			[2.0, 4.0, 6.0 + 1e-15, 1.0],
+			[1.0, 0.0, 1.0, 1.0],
+			[0.0, 0.0, 0.0, 1.0],
+		];
+		assert_eq!(
+			Err(MatrixError::Singular),
+			affine_inverse_2d(&nearly_singular)
+		);
+		assert_eq!(
+			Err(MatrixError::Singular),
+			affine_inverse_3d(&nearly_singular_3d)
+		);
+		// small but well conditioned blocks still invert
+		assert!(affine_inverse_3d(&scale_3d(1e-8, 1e-8, 1e-8)).is_ok());
+		let anisotropic = scale_3d(1e6, 1e-6, 1.0);
+		assert_eq!(
+			Ok(scale_3d(1e-6, 1e6, 1.0)),
+			affine_inverse_3d(&anisotropic)
+		);
+	}
+	#[test]
+	fn test_rigid_inverse() {
+		let transform_3d = translation_3d(1.0, -2.0, 3.0)
+			.matrix_mult_matrix(&rotation_axis_angle(&[1.0, 2.0, 3.0], 0.8).unwrap());
+		let transform_2d: Matrix2D = [[0.0, -1.0, 2.0], [1.0, 0.0, 3.0], [0.0, 0.0, 1.0]];
+
+		let rigid_3d = rigid_inverse_3d(&transform_3d).unwrap();
+		let affine_3d = affine_inverse_3d(&transform_3d).unwrap();
+		for row in 0..4 {
+			for column in 0..4 {
+				assert!((rigid_3d[row][column] - affine_3d[row][column]).abs() < 1e-9);
+			}
+		}
+		assert_eq!(
+			[[0.0, 1.0, -3.0], [-1.0, 0.0, 2.0], [0.0, 0.0, 1.0]],
+			rigid_inverse_2d(&transform_2d).unwrap()
+		);
+		let rotation = rigid_inverse_3d(&rotation_z(0.3)).unwrap();
+		let expected = rotation_z(-0.3);
+		for row in 0..4 {
+			for column in 0..4 {
+				assert!((rotation[row][column] - expected[row][column]).abs() < 1e-12);
+			}
+		}
+	}
+	#[test]
+	fn test_inverse_non_homogeneous() {
+		let mut projective: Matrix3D = translation_3d(1.0, 2.0, 3.0);
+		projective[3][2] = -1.0;
+		let mut scaled: Matrix2D = I3;
+		scaled[2][2] = 2.0;
+
+		assert_eq!(
+			Err(MatrixError::NonHomogeneous),
+			affine_inverse_3d(&projective)
+		);
+		assert_eq!(
+			Err(MatrixError::NonHomogeneous),
+			rigid_inverse_3d(&projective)
+		);
+		assert_eq!(Err(MatrixError::NonHomogeneous), affine_inverse_2d(&scaled));
+		assert_eq!(Err(MatrixError::NonHomogeneous), rigid_inverse_2d(&scaled));
+	}
+}
@@ -0,0 +1,249 @@
+use super::{rotation_x, rotation_y, rotation_z};
+use crate::{Axis, Fsize, Matrix3D, MatrixOperations};
+
+//below this cosine of the middle angle (sine for proper Euler orders) the
+//first and third axes are considered aligned
+const GIMBAL_LOCK_TOLERANCE: Fsize = 1e-7;
+
+/// The sequence of axes the three Euler angles rotate about.
+///
+/// The first six are Tait-Bryan orders, the last six proper Euler orders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EulerOrder {
+	XYZ,
+	XZY,
+	YXZ,
+	YZX,
+	ZXY,
+	ZYX,
+	XYX,
+	XZX,
+	YXY,
+	YZY,
+	ZXZ,
+	ZYZ,
+}
+
+/// Whether each rotation is about the rotated (intrinsic) or the fixed
+/// (extrinsic) axes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EulerConvention {
+	Intrinsic,
+	Extrinsic,
+}
+
+/// Three angles, in radians, applied about the axes of `order` in sequence.
+///
+/// An intrinsic `XYZ` rotation is `Rx(angles[0])·Ry(angles[1])·Rz(angles[2])`,
+/// while an extrinsic one is `Rz(angles[2])·Ry(angles[1])·Rx(angles[0])`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EulerAngles {
+	pub angles: [Fsize; 3],
+	pub order: EulerOrder,
+	pub convention: EulerConvention,
+}
+
+impl EulerOrder {
+	pub fn axes(&self) -> [Axis; 3] {
+		match self {
+			EulerOrder::XYZ => [Axis::X, Axis::Y, Axis::Z],
+			EulerOrder::XZY => [Axis::X, Axis::Z, Axis::Y],
+			EulerOrder::YXZ => [Axis::Y, Axis::X, Axis::Z],
+			EulerOrder::YZX => [Axis::Y, Axis::Z, Axis::X],
+			EulerOrder::ZXY => [Axis::Z, Axis::X, Axis::Y],
+			EulerOrder::ZYX => [Axis::Z, Axis::Y, Axis::X],
+			EulerOrder::XYX => [Axis::X, Axis::Y, Axis::X],
+			EulerOrder::XZX => [Axis::X, Axis::Z, Axis::X],
+			EulerOrder::YXY => [Axis::Y, Axis::X, Axis::Y],
+			EulerOrder::YZY => [Axis::Y, Axis::Z, Axis::Y],
+			EulerOrder::ZXZ => [Axis::Z, Axis::X, Axis::Z],
+			EulerOrder::ZYZ => [Axis::Z, Axis::Y, Axis::Z],
+		}
+	}
+	pub fn is_proper(&self) -> bool {
+		let axes = self.axes();
+		axes[0] == axes[2]
+	}
+}
+
+impl EulerAngles {
+	pub fn new(angles: [Fsize; 3], order: EulerOrder, convention: EulerConvention) -> Self {
+		EulerAngles {
+			angles,
+			order,
+			convention,
+		}
+	}
+
+	/// Builds the homogeneous rotation matrix described by the angles.
+	pub fn to_matrix(&self) -> Matrix3D {
+		let axes = self.order.axes();
+		let rotations: [Matrix3D; 3] = std::array::from_fn(|index| {
+			let angle = self.angles[index];
+			match axes[index] {
+				Axis::X => rotation_x(angle),
+				Axis::Y => rotation_y(angle),
+				Axis::Z => rotation_z(angle),
+			}
+		});
+		match self.convention {
+			EulerConvention::Intrinsic => rotations[0]
+				.matrix_mult_matrix(&rotations[1])
+				.matrix_mult_matrix(&rotations[2]),
+			EulerConvention::Extrinsic => rotations[2]
+				.matrix_mult_matrix(&rotations[1])
+				.matrix_mult_matrix(&rotations[0]),
+		}
+	}
+
+	/// Recovers the angles of the rotation held in the upper left 3x3 block
+	/// of `matrix`.
+	///
+	/// The middle angle lies in `[-π/2, π/2]` for Tait-Bryan orders and in
+	/// `[0, π]` for proper Euler orders. In gimbal lock only the sum or
+	/// difference of the first and third angles is defined, so the third
+	/// angle is set to zero for intrinsic rotations and the first angle for
+	/// extrinsic ones.
+	pub fn from_matrix(
+		matrix: &Matrix3D,
+		order: EulerOrder,
+		convention: EulerConvention,
+	) -> EulerAngles {
+		let axes = order.axes();
+		let angles = match convention {
+			EulerConvention::Intrinsic => intrinsic_angles(matrix, axes),
+			// an extrinsic a, b, c rotation is the intrinsic c, b, a rotation
+			EulerConvention::Extrinsic => {
+				let [first, second, third] = intrinsic_angles(matrix, [axes[2], axes[1], axes[0]]);
+				[third, second, first]
+			}
+		};
+		EulerAngles::new(angles, order, convention)
+	}
+}
+
+fn axis_index(axis: Axis) -> usize {
+	match axis {
+		Axis::X => 0,
+		Axis::Y => 1,
+		Axis::Z => 2,
+	}
+}
+
+//angles of R = Ri(a)·Rj(b)·Rk(c), or Ri(a)·Rj(b)·Ri(c) for proper orders
+fn intrinsic_angles(matrix: &Matrix3D, axes: [Axis; 3]) -> [Fsize; 3] {
+	let i = axis_index(axes[0]);
+	let j = axis_index(axes[1]);
+	let k = 3 - i - j;
+	let m = matrix;
+	// +1 when i, j, k is a cyclic permutation of x, y, z
+	let parity: Fsize = if (j + 3 - i) % 3 == 1 { 1.0 } else { -1.0 };
+
+	if axes[0] == axes[2] {
+		let sin_b = (m[i][j] * m[i][j] + m[i][k] * m[i][k]).sqrt();
+		let b = sin_b.atan2(m[i][i]);
+		if sin_b < GIMBAL_LOCK_TOLERANCE {
+			return [(parity * m[k][j]).atan2(m[j][j]), b, 0.0];
+		}
+		[
+			m[j][i].atan2(-parity * m[k][i]),
+			b,
+			m[i][j].atan2(parity * m[i][k]),
+		]
+	} else {
+		let cos_b = (m[i][i] * m[i][i] + m[i][j] * m[i][j]).sqrt();
+		let b = (parity * m[i][k]).atan2(cos_b);
+		if cos_b < GIMBAL_LOCK_TOLERANCE {
+			return [(parity * m[k][j]).atan2(m[j][j]), b, 0.0];
+		}
+		[
+			(-parity * m[j][k]).atan2(m[k][k]),
+			b,
+			(-parity * m[i][j]).atan2(m[i][i]),
+		]
+	}
+}
+
+#[cfg(test)]
+mod test_euler {
+	use super::{EulerAngles, EulerConvention, EulerOrder};
+	use crate::transformations::{rotation_x, rotation_y, rotation_z};
+	use crate::{Fsize, Matrix3D, MatrixOperations};
+
+	const ORDERS: [EulerOrder; 12] = [
+		EulerOrder::XYZ,
+		EulerOrder::XZY,
+		EulerOrder::YXZ,
+		EulerOrder::YZX,
+		EulerOrder::ZXY,
+		EulerOrder::ZYX,
+		EulerOrder::XYX,
+		EulerOrder::XZX,
+		EulerOrder::YXY,
+		EulerOrder::YZY,
+		EulerOrder::ZXZ,
+		EulerOrder::ZYZ,
+	];
+	const CONVENTIONS: [EulerConvention; 2] =
+		[EulerConvention::Intrinsic, EulerConvention::Extrinsic];
+
+	fn assert_matrix_close(expected: &Matrix3D, result: &Matrix3D) {
+		for row in 0..4 {
+			for column in 0..4 {
+				assert!((expected[row][column] - result[row][column]).abs() < 1e-6);
+			}
+		}
+	}
+
+	#[test]
+	fn test_euler_to_matrix() {
+		let intrinsic =
+			EulerAngles::new([0.1, 0.2, 0.3], EulerOrder::XYZ, EulerConvention::Intrinsic);
+		let extrinsic =
+			EulerAngles::new([0.1, 0.2, 0.3], EulerOrder::ZYX, EulerConvention::Extrinsic);
+
+		assert_matrix_close(
+			&rotation_x(0.1)
+				.matrix_mult_matrix(&rotation_y(0.2))
+				.matrix_mult_matrix(&rotation_z(0.3)),
+			&intrinsic.to_matrix(),
+		);
+		assert_matrix_close(
+			&rotation_x(0.3)
+				.matrix_mult_matrix(&rotation_y(0.2))
+				.matrix_mult_matrix(&rotation_z(0.1)),
+			&extrinsic.to_matrix(),
+		);
+	}
+	#[test]
+	fn test_euler_round_trip() {
+		for order in ORDERS.iter() {
+			let angles: [Fsize; 3] = if order.is_proper() {
+				[0.4, 1.1, -2.3]
+			} else {
+				[0.4, -1.1, 2.3]
+			};
+			for convention in CONVENTIONS.iter() {
+				let euler = EulerAngles::new(angles, *order, *convention);
+				let result = EulerAngles::from_matrix(&euler.to_matrix(), *order, *convention);
+				for (expected, angle) in angles.iter().zip(result.angles.iter()) {
+					assert!((expected - angle).abs() < 1e-6);
+				}
+			}
+		}
+	}
+	#[test]
+	fn test_euler_gimbal_lock() {
+		let half_pi = std::f64::consts::FRAC_PI_2 as Fsize;
+		for order in ORDERS.iter() {
+			let middle = if order.is_proper() { 0.0 } else { half_pi };
+			for convention in CONVENTIONS.iter() {
+				let euler = EulerAngles::new([0.5, middle, 0.3], *order, *convention);
+				let matrix = euler.to_matrix();
+				let result = EulerAngles::from_matrix(&matrix, *order, *convention);
+				assert_matrix_close(&matrix, &result.to_matrix());
+				assert!((middle - result.angles[1]).abs() < 1e-6);
+			}
+		}
+	}
+}
@@ -0,0 +1,16 @@
+mod affine;
+mod euler;
+mod projection;
+mod rotation;
+mod view;
+
+pub use affine::{
+	affine_inverse_2d, affine_inverse_3d, reflection_2d, reflection_3d, rigid_inverse_2d,
+	rigid_inverse_3d, scale_2d, scale_3d, shear_2d, shear_3d, translation_2d, translation_3d,
+};
+pub use euler::{EulerAngles, EulerConvention, EulerOrder};
+pub use projection::{frustum, orthographic, perspective, perspective_infinite, DepthRange};
+pub use rotation::{
+	axis_angle_from_matrix, rotation_axis_angle, rotation_x, rotation_y, rotation_z,
+};
+pub use view::{look_at, look_to, Handedness};
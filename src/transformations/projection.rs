@@ -0,0 +1,207 @@
+use crate::{Fsize, Matrix3D};
+
+/// The range normalized device depth is mapped to, from the near plane to the
+/// far plane.
+///
+/// All projections are right handed, with the camera looking down the
+/// negative Z axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DepthRange {
+	/// `-1..1`, as used by OpenGL.
+	NegativeOneToOne,
+	/// `0..1`, as used by Vulkan, DirectX and Metal.
+	ZeroToOne,
+	/// `1..0`, the reversed-Z mapping.
+	OneToZero,
+}
+
+/// Builds a perspective projection from the vertical field of view
+/// `fov_y_rad`, the width over height `aspect` ratio and the distances to the
+/// near and far planes. `far` may be `Fsize::INFINITY`.
+pub fn perspective(
+	fov_y_rad: Fsize,
+	aspect: Fsize,
+	near: Fsize,
+	far: Fsize,
+	depth: DepthRange,
+) -> Matrix3D {
+	let top = near * (fov_y_rad / 2.0).tan();
+	let right = top * aspect;
+	frustum(-right, right, -top, top, near, far, depth)
+}
+
+/// Builds a perspective projection whose far plane is at infinity.
+pub fn perspective_infinite(
+	fov_y_rad: Fsize,
+	aspect: Fsize,
+	near: Fsize,
+	depth: DepthRange,
+) -> Matrix3D {
+	perspective(fov_y_rad, aspect, near, Fsize::INFINITY, depth)
+}
+
+/// Builds a perspective projection from the bounds of the view frustum on the
+/// near plane. `far` may be `Fsize::INFINITY`.
+pub fn frustum(
+	left: Fsize,
+	right: Fsize,
+	bottom: Fsize,
+	top: Fsize,
+	near: Fsize,
+	far: Fsize,
+	depth: DepthRange,
+) -> Matrix3D {
+	// depth is (a·z + b) / -z, the limits below follow from far tending to infinity
+	let (a, b) = match (depth, far.is_infinite()) {
+		(DepthRange::NegativeOneToOne, false) => (
+			-(far + near) / (far - near),
+			-2.0 * far * near / (far - near),
+		),
+		(DepthRange::NegativeOneToOne, true) => (-1.0, -2.0 * near),
+		(DepthRange::ZeroToOne, false) => (-far / (far - near), -far * near / (far - near)),
+		(DepthRange::ZeroToOne, true) => (-1.0, -near),
+		(DepthRange::OneToZero, false) => (near / (far - near), far * near / (far - near)),
+		(DepthRange::OneToZero, true) => (0.0, near),
+	};
+	[
+		[
+			2.0 * near / (right - left),
+			0.0,
+			(right + left) / (right - left),
+			0.0,
+		],
+		[
+			0.0,
+			2.0 * near / (top - bottom),
+			(top + bottom) / (top - bottom),
+			0.0,
+		],
+		[0.0, 0.0, a, b],
+		[0.0, 0.0, -1.0, 0.0],
+	]
+}
+
+/// Builds an orthographic projection from the bounds of the view volume.
+pub fn orthographic(
+	left: Fsize,
+	right: Fsize,
+	bottom: Fsize,
+	top: Fsize,
+	near: Fsize,
+	far: Fsize,
+	depth: DepthRange,
+) -> Matrix3D {
+	// depth is c·z + d
+	let (c, d) = match depth {
+		DepthRange::NegativeOneToOne => (-2.0 / (far - near), -(far + near) / (far - near)),
+		DepthRange::ZeroToOne => (-1.0 / (far - near), -near / (far - near)),
+		DepthRange::OneToZero => (1.0 / (far - near), far / (far - near)),
+	};
+	[
+		[
+			2.0 / (right - left),
+			0.0,
+			0.0,
+			-(right + left) / (right - left),
+		],
+		[
+			0.0,
+			2.0 / (top - bottom),
+			0.0,
+			-(top + bottom) / (top - bottom),
+		],
+		[0.0, 0.0, c, d],
+		[0.0, 0.0, 0.0, 1.0],
+	]
+}
+
+#[cfg(test)]
+mod test_projection {
+	use super::{frustum, orthographic, perspective, perspective_infinite, DepthRange};
+	use crate::{Fsize, Matrix3D, MatrixOperations, Point3D};
+
+	fn project(matrix: &Matrix3D, point: &Point3D) -> [Fsize; 3] {
+		let clip = matrix.matrix_mult_point(point);
+		[clip[0] / clip[3], clip[1] / clip[3], clip[2] / clip[3]]
+	}
+	fn assert_close(expected: Fsize, result: Fsize) {
+		assert!((expected - result).abs() < 1e-6);
+	}
+
+	#[test]
+	fn test_perspective_depth_ranges() {
+		let half_pi = std::f64::consts::FRAC_PI_2 as Fsize;
+		let ranges = [
+			(DepthRange::NegativeOneToOne, -1.0, 1.0),
+			(DepthRange::ZeroToOne, 0.0, 1.0),
+			(DepthRange::OneToZero, 1.0, 0.0),
+		];
+		for (depth, near_depth, far_depth) in ranges.iter() {
+			let matrix = perspective(half_pi, 2.0, 1.0, 10.0, *depth);
+			let near = project(&matrix, &[2.0, 1.0, -1.0, 1.0]);
+			let far = project(&matrix, &[20.0, -10.0, -10.0, 1.0]);
+
+			assert_close(1.0, near[0]);
+			assert_close(1.0, near[1]);
+			assert_close(*near_depth, near[2]);
+			assert_close(1.0, far[0]);
+			assert_close(-1.0, far[1]);
+			assert_close(*far_depth, far[2]);
+		}
+	}
+	#[test]
+	fn test_perspective_infinite() {
+		let half_pi = std::f64::consts::FRAC_PI_2 as Fsize;
+		let ranges = [
+			(DepthRange::NegativeOneToOne, -1.0, 1.0),
+			(DepthRange::ZeroToOne, 0.0, 1.0),
+			(DepthRange::OneToZero, 1.0, 0.0),
+		];
+		for (depth, near_depth, far_depth) in ranges.iter() {
+			let matrix = perspective_infinite(half_pi, 1.0, 0.5, *depth);
+			let near = project(&matrix, &[0.0, 0.0, -0.5, 1.0]);
+			let far = project(&matrix, &[0.0, 0.0, -1e12, 1.0]);
+
+			assert_close(*near_depth, near[2]);
+			assert_close(*far_depth, far[2]);
+		}
+	}
+	#[test]
+	fn test_frustum() {
+		let matrix = frustum(-1.0, 3.0, -2.0, 2.0, 2.0, 8.0, DepthRange::NegativeOneToOne);
+		let corner = project(&matrix, &[3.0, 2.0, -2.0, 1.0]);
+		let other_corner = project(&matrix, &[-4.0, -8.0, -8.0, 1.0]);
+
+		assert_eq!(
+			[
+				[1.0, 0.0, 0.5, 0.0],
+				[0.0, 1.0, 0.0, 0.0],
+				[0.0, 0.0, -10.0 / 6.0, -32.0 / 6.0],
+				[0.0, 0.0, -1.0, 0.0],
+			],
+			matrix
+		);
+		for (expected, result) in [1.0, 1.0, -1.0].iter().zip(corner.iter()) {
+			assert_close(*expected, *result);
+		}
+		for (expected, result) in [-1.0, -1.0, 1.0].iter().zip(other_corner.iter()) {
+			assert_close(*expected, *result);
+		}
+	}
+	#[test]
+	fn test_orthographic() {
+		let ranges = [
+			(DepthRange::NegativeOneToOne, -1.0, 1.0),
+			(DepthRange::ZeroToOne, 0.0, 1.0),
+			(DepthRange::OneToZero, 1.0, 0.0),
+		];
+		for (depth, near_depth, far_depth) in ranges.iter() {
+			let matrix = orthographic(-2.0, 2.0, -1.0, 3.0, 1.0, 5.0, *depth);
+			let near = project(&matrix, &[-2.0, 3.0, -1.0, 1.0]);
+			let far = project(&matrix, &[2.0, -1.0, -5.0, 1.0]);
+
+			assert_eq!([-1.0, 1.0, *near_depth], near);
+			assert_eq!([1.0, -1.0, *far_depth], far);
+		}
+	}
+}
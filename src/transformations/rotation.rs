@@ -0,0 +1,182 @@
+use crate::{Fsize, Matrix3D, MatrixError, VecScalingProjection, VectorOps};
+
+/// Builds a homogeneous matrix rotating `theta_rad` radians about the X axis.
+pub fn rotation_x(theta_rad: Fsize) -> Matrix3D {
+	let (sin, cos) = theta_rad.sin_cos();
+	[
+		[1.0, 0.0, 0.0, 0.0],
+		[0.0, cos, -sin, 0.0],
+		[0.0, sin, cos, 0.0],
+		[0.0, 0.0, 0.0, 1.0],
+	]
+}
+
+/// Builds a homogeneous matrix rotating `theta_rad` radians about the Y axis.
+pub fn rotation_y(theta_rad: Fsize) -> Matrix3D {
+	let (sin, cos) = theta_rad.sin_cos();
+	[
+		[cos, 0.0, sin, 0.0],
+		[0.0, 1.0, 0.0, 0.0],
+		[-sin, 0.0, cos, 0.0],
+		[0.0, 0.0, 0.0, 1.0],
+	]
+}
+
+/// Builds a homogeneous matrix rotating `theta_rad` radians about the Z axis.
+pub fn rotation_z(theta_rad: Fsize) -> Matrix3D {
+	let (sin, cos) = theta_rad.sin_cos();
+	[
+		[cos, -sin, 0.0, 0.0],
+		[sin, cos, 0.0, 0.0],
+		[0.0, 0.0, 1.0, 0.0],
+		[0.0, 0.0, 0.0, 1.0],
+	]
+}
+
+/// Builds a homogeneous matrix rotating `theta_rad` radians about `axis` using
+/// Rodrigues' rotation formula. The axis does not need to be normalized.
+pub fn rotation_axis_angle(axis: &[Fsize; 3], theta_rad: Fsize) -> Result<Matrix3D, MatrixError> {
+	let magnitude = axis.try_magnitude()?;
+	if magnitude == 0.0 {
+		return Err(MatrixError::ZeroMagnitude);
+	}
+	let [x, y, z] = axis.vec_scal(1.0 / magnitude);
+	let (sin, cos) = theta_rad.sin_cos();
+	let one_minus_cos = 1.0 - cos;
+	Ok([
+		[
+			cos + x * x * one_minus_cos,
+			x * y * one_minus_cos - z * sin,
+			x * z * one_minus_cos + y * sin,
+			0.0,
+		],
+		[
+			y * x * one_minus_cos + z * sin,
+			cos + y * y * one_minus_cos,
+			y * z * one_minus_cos - x * sin,
+			0.0,
+		],
+		[
+			z * x * one_minus_cos - y * sin,
+			z * y * one_minus_cos + x * sin,
+			cos + z * z * one_minus_cos,
+			0.0,
+		],
+		[0.0, 0.0, 0.0, 1.0],
+	])
+}
+
+/// Extracts the unit axis and the angle, in `[0, π]`, of the rotation held in
+/// the upper left 3x3 block of `matrix`.
+///
+/// When the angle is zero the axis is undefined and `[1, 0, 0]` is returned.
+pub fn axis_angle_from_matrix(matrix: &Matrix3D) -> ([Fsize; 3], Fsize) {
+	let trace = matrix[0][0] + matrix[1][1] + matrix[2][2];
+	let cos = ((trace - 1.0) / 2.0).clamp(-1.0, 1.0);
+	// twice sin(theta) times the axis, from the skew symmetric part
+	let skew: [Fsize; 3] = [
+		matrix[2][1] - matrix[1][2],
+		matrix[0][2] - matrix[2][0],
+		matrix[1][0] - matrix[0][1],
+	];
+	let skew_mag = skew.magnitude();
+	let theta = (skew_mag / 2.0).atan2(cos);
+
+	if cos >= 0.0 {
+		if skew_mag <= Fsize::EPSILON {
+			return ([1.0, 0.0, 0.0], 0.0);
+		}
+		return (skew.vec_scal(1.0 / skew_mag), theta);
+	}
+
+	// near π the skew symmetric part vanishes, so recover the axis from the
+	// symmetric part, cos·I + (1 - cos)·aaᵀ, using its largest diagonal
+	let outer = |row: usize, column: usize| {
+		let identity = if row == column { cos } else { 0.0 };
+		((matrix[row][column] + matrix[column][row]) / 2.0 - identity) / (1.0 - cos)
+	};
+	let pivot = (0..3)
+		.max_by(|&a, &b| outer(a, a).partial_cmp(&outer(b, b)).unwrap())
+		.unwrap_or(0);
+	let pivot_value = outer(pivot, pivot).max(0.0).sqrt();
+	let mut axis: [Fsize; 3] = [0.0; 3];
+	for (index, value) in axis.iter_mut().enumerate() {
+		*value = if index == pivot {
+			pivot_value
+		} else {
+			outer(pivot, index) / pivot_value
+		};
+	}
+	if axis.dot(&skew) < 0.0 {
+		axis = axis.vec_scal(-1.0);
+	}
+	(axis.vec_scal(1.0 / axis.magnitude()), theta)
+}
+
+#[cfg(test)]
+mod test_rotation {
+	use super::{axis_angle_from_matrix, rotation_axis_angle, rotation_x, rotation_y, rotation_z};
+	use crate::{Axis, Fsize, MatrixError, MatrixOperations, Point3D, ThreeDimMatrixOps, I4};
+
+	#[test]
+	fn test_rotation_builders() {
+		let theta: Fsize = 0.7;
+		let point: Point3D = [1.0, 2.0, 3.0, 1.0];
+
+		let rotations = [
+			(rotation_x(theta), Axis::X),
+			(rotation_y(theta), Axis::Y),
+			(rotation_z(theta), Axis::Z),
+		];
+		for (matrix, axis) in rotations.iter() {
+			let by_matrix = matrix.matrix_mult_point(&point);
+			let by_point = point.to_vec().matrix_three_dim_rotate(*axis, theta, true);
+			for index in 0..4 {
+				assert!((by_matrix[index] - by_point[index]).abs() < 1e-6);
+			}
+		}
+		assert_eq!(I4, rotation_x(0.0));
+		assert_eq!(I4, rotation_z(0.0));
+	}
+	#[test]
+	fn test_rotation_axis_angle() {
+		let theta: Fsize = 0.7;
+		let about_x = rotation_axis_angle(&[2.0, 0.0, 0.0], theta).unwrap();
+		let about_z = rotation_axis_angle(&[0.0, 0.0, 1.0], theta).unwrap();
+		for row in 0..4 {
+			for column in 0..4 {
+				assert!((about_x[row][column] - rotation_x(theta)[row][column]).abs() < 1e-6);
+				assert!((about_z[row][column] - rotation_z(theta)[row][column]).abs() < 1e-6);
+			}
+		}
+		assert_eq!(
+			Err(MatrixError::ZeroMagnitude),
+			rotation_axis_angle(&[0.0, 0.0, 0.0], theta)
+		);
+	}
+	#[test]
+	fn test_axis_angle_from_matrix() {
+		let norm = (3.0 as Fsize).sqrt();
+		let axis: [Fsize; 3] = [1.0 / norm, -1.0 / norm, 1.0 / norm];
+		let angles: [Fsize; 5] = [
+			1e-7,
+			0.7,
+			2.5,
+			std::f64::consts::PI as Fsize - 1e-7,
+			std::f64::consts::PI as Fsize,
+		];
+
+		for &theta in angles.iter() {
+			let matrix = rotation_axis_angle(&axis, theta).unwrap();
+			let (result_axis, result_theta) = axis_angle_from_matrix(&matrix);
+			assert!((result_theta - theta).abs() < 1e-6);
+			let rebuilt = rotation_axis_angle(&result_axis, result_theta).unwrap();
+			for row in 0..4 {
+				for column in 0..4 {
+					assert!((rebuilt[row][column] - matrix[row][column]).abs() < 1e-6);
+				}
+			}
+		}
+		assert_eq!(([1.0, 0.0, 0.0], 0.0), axis_angle_from_matrix(&I4));
+	}
+}
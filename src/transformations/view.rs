@@ -0,0 +1,186 @@
+use crate::{Fsize, Matrix3D, MatrixError, Point3D, VecScalingProjection, VectorOps};
+
+/// Whether view space is right handed, looking down the negative Z axis, or
+/// left handed, looking down the positive Z axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Handedness {
+	Right,
+	Left,
+}
+
+/// Builds a view matrix for a camera at `eye` looking towards `target`.
+///
+/// The last, homogeneous, component of each argument is ignored. Fails when
+/// `eye` and `target` coincide, `up` is zero or the view direction is
+/// parallel to `up`.
+pub fn look_at(
+	eye: &Point3D,
+	target: &Point3D,
+	up: &Point3D,
+	handedness: Handedness,
+) -> Result<Matrix3D, MatrixError> {
+	let direction: Point3D = [
+		target[0] - eye[0],
+		target[1] - eye[1],
+		target[2] - eye[2],
+		0.0,
+	];
+	look_to(eye, &direction, up, handedness)
+}
+
+/// Builds a view matrix for a camera at `eye` looking along `direction`.
+///
+/// The last, homogeneous, component of each argument is ignored. Fails when
+/// `direction` or `up` is zero, or when they are parallel.
+pub fn look_to(
+	eye: &Point3D,
+	direction: &Point3D,
+	up: &Point3D,
+	handedness: Handedness,
+) -> Result<Matrix3D, MatrixError> {
+	let eye = [eye[0], eye[1], eye[2]];
+	let up = [up[0], up[1], up[2]].try_normalize()?;
+	let forward = [direction[0], direction[1], direction[2]].try_normalize()?;
+	let (side, view_z) = match handedness {
+		Handedness::Right => (side_axis(&forward.cross(&up))?, forward.vec_scal(-1.0)),
+		Handedness::Left => (side_axis(&up.cross(&forward))?, forward),
+	};
+	let camera_up = match handedness {
+		Handedness::Right => side.cross(&forward),
+		Handedness::Left => forward.cross(&side),
+	};
+	Ok([
+		basis_row(&side, &eye),
+		basis_row(&camera_up, &eye),
+		basis_row(&view_z, &eye),
+		[0.0, 0.0, 0.0, 1.0],
+	])
+}
+
+fn basis_row(axis: &[Fsize; 3], eye: &[Fsize; 3]) -> [Fsize; 4] {
+	[axis[0], axis[1], axis[2], -axis.dot(eye)]
+}
+
+//the cross product of two unit vectors has the magnitude of the sine of the
+//angle between them, rounding leaves a residue of around epsilon when they
+//are parallel in exact arithmetic, which must not pass as a basis axis
+fn side_axis(cross: &[Fsize; 3]) -> Result<[Fsize; 3], MatrixError> {
+	if cross.try_magnitude()? <= Fsize::EPSILON.sqrt() {
+		return Err(MatrixError::ZeroMagnitude);
+	}
+	cross.try_normalize()
+}
+
+#[cfg(test)]
+mod test_view {
+	use super::{look_at, look_to, Handedness};
+	use crate::{MatrixError, MatrixOperations, Point3D, VectorOps, I4};
+
+	fn assert_point_close(expected: &Point3D, result: &Point3D) {
+		for index in 0..4 {
+			assert!((expected[index] - result[index]).abs() < 1e-6);
+		}
+	}
+
+	#[test]
+	fn test_look_at_right_handed() {
+		let up: Point3D = [0.0, 1.0, 0.0, 0.0];
+		let origin: Point3D = [0.0, 0.0, 0.0, 1.0];
+
+		// the default OpenGL camera is the identity
+		assert_eq!(
+			Ok(I4),
+			look_at(&origin, &[0.0, 0.0, -1.0, 1.0], &up, Handedness::Right)
+		);
+
+		let eye: Point3D = [1.0, 2.0, 3.0, 1.0];
+		let view = look_at(&eye, &[1.0, 2.0, 0.0, 1.0], &up, Handedness::Right).unwrap();
+		assert_point_close(&[0.0, 0.0, 0.0, 1.0], &view.matrix_mult_point(&eye));
+		assert_point_close(
+			&[0.0, 0.0, -3.0, 1.0],
+			&view.matrix_mult_point(&[1.0, 2.0, 0.0, 1.0]),
+		);
+
+		let view = look_at(&origin, &[1.0, 0.0, 0.0, 1.0], &up, Handedness::Right).unwrap();
+		assert_point_close(
+			&[0.0, 0.0, -5.0, 1.0],
+			&view.matrix_mult_point(&[5.0, 0.0, 0.0, 1.0]),
+		);
+		assert_point_close(
+			&[0.0, 2.0, 0.0, 1.0],
+			&view.matrix_mult_point(&[0.0, 2.0, 0.0, 1.0]),
+		);
+	}
+	#[test]
+	fn test_look_at_left_handed() {
+		let up: Point3D = [0.0, 1.0, 0.0, 0.0];
+		let origin: Point3D = [0.0, 0.0, 0.0, 1.0];
+
+		// the default DirectX camera is the identity
+		assert_eq!(
+			Ok(I4),
+			look_at(&origin, &[0.0, 0.0, 1.0, 1.0], &up, Handedness::Left)
+		);
+
+		let view = look_at(&origin, &[1.0, 0.0, 0.0, 1.0], &up, Handedness::Left).unwrap();
+		assert_point_close(
+			&[0.0, 0.0, 5.0, 1.0],
+			&view.matrix_mult_point(&[5.0, 0.0, 0.0, 1.0]),
+		);
+		assert_point_close(
+			&[1.0, 0.0, 0.0, 1.0],
+			&view.matrix_mult_point(&[0.0, 0.0, -1.0, 1.0]),
+		);
+	}
+	#[test]
+	fn test_look_to() {
+		let eye: Point3D = [1.0, 2.0, 3.0, 1.0];
+		let target: Point3D = [4.0, -1.0, 2.0, 1.0];
+		let up: Point3D = [0.0, 0.0, 1.0, 0.0];
+		let direction: Point3D = [3.0, -3.0, -1.0, 0.0];
+
+		for handedness in [Handedness::Right, Handedness::Left].iter() {
+			assert_eq!(
+				look_at(&eye, &target, &up, *handedness),
+				look_to(&eye, &direction, &up, *handedness)
+			);
+		}
+		let parallel: Point3D = [0.0, 0.0, 2.0, 0.0];
+		assert_eq!(
+			Err(MatrixError::ZeroMagnitude),
+			look_to(&eye, &parallel, &up, Handedness::Right)
+		);
+		let tilted: Point3D = [0.1, 0.7, 0.3, 0.0];
+		// parallel to up, but the cross product leaves a rounding residue
+		let tilted_direction = tilted.vec_scal(3.0);
+		assert_eq!(
+			Err(MatrixError::ZeroMagnitude),
+			look_to(&eye, &tilted_direction, &tilted, Handedness::Right)
+		);
+		// the check does not depend on the scale of either vector
+		assert_eq!(
+			Err(MatrixError::ZeroMagnitude),
+			look_to(
+				&eye,
+				&tilted_direction,
+				&tilted.vec_scal(1e6),
+				Handedness::Right
+			)
+		);
+		let expected = look_to(&eye, &direction, &up, Handedness::Right).unwrap();
+		let tiny = look_to(
+			&eye,
+			&direction.vec_scal(1e-17),
+			&up.vec_scal(1e-17),
+			Handedness::Right,
+		)
+		.unwrap();
+		for (expected_row, tiny_row) in expected.iter().zip(tiny.iter()) {
+			assert_point_close(expected_row, tiny_row);
+		}
+		assert_eq!(
+			Err(MatrixError::ZeroMagnitude),
+			look_at(&eye, &eye, &up, Handedness::Left)
+		);
+	}
+}
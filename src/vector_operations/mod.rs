@@ -1,5 +1,8 @@
+mod vec_linear_operator;
+mod vec_matrix_inverse;
 mod vec_matrix_ops;
 mod vec_point;
 mod vec_scaling_projection;
+mod vec_three_dim_matrix_ops;
 mod vec_two_dim_matrix_ops;
 mod vector_ops;
@@ -0,0 +1,55 @@
+use crate::{check_operand_length, matrix_shape, LinearOperator, MatrixError};
+use num::Zero;
+use std::ops::{Add, Mul};
+
+impl<T> LinearOperator<T> for Vec<Vec<T>>
+where
+	T: Add<T, Output = T> + Mul<T, Output = T> + Zero + Copy,
+{
+	fn try_apply(&self, x: &[T]) -> Result<Vec<T>, MatrixError> {
+		let shape = matrix_shape(self)?;
+		check_operand_length(shape, shape.1, x)?;
+		Ok(self
+			.iter()
+			.map(|row| {
+				row.iter()
+					.zip(x.iter())
+					.fold(T::zero(), |sum, (a, x)| sum + *a * *x)
+			})
+			.collect())
+	}
+	fn try_apply_transpose(&self, x: &[T]) -> Result<Vec<T>, MatrixError> {
+		let shape = matrix_shape(self)?;
+		check_operand_length(shape, shape.0, x)?;
+		let mut y = vec![T::zero(); shape.1];
+		for (row, x) in self.iter().zip(x.iter()) {
+			for (y, a) in y.iter_mut().zip(row.iter()) {
+				*y = *y + *a * *x;
+			}
+		}
+		Ok(y)
+	}
+}
+
+#[cfg(test)]
+mod test_vec_linear_operator {
+	use crate::{Fsize, LinearOperator, MatrixError, MatrixOperations};
+
+	#[test]
+	fn test_vec_apply() {
+		let matrix: Vec<Vec<Fsize>> = vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]];
+
+		assert_eq!(vec![14.0, 32.0], matrix.apply(&[1.0, 2.0, 3.0]));
+		assert_eq!(
+			matrix.transpose().apply(&[1.0, -1.0]),
+			matrix.apply_transpose(&[1.0, -1.0])
+		);
+		assert_eq!(
+			Err(MatrixError::DimensionMismatch {
+				lhs: (2, 3),
+				rhs: (3, 1)
+			}),
+			matrix.try_apply_transpose(&[1.0, 2.0, 3.0])
+		);
+	}
+}
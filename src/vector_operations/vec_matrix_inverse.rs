@@ -0,0 +1,74 @@
+use crate::{gauss_jordan_inverse, square_size, MatrixError, MatrixInverse};
+use num::Float;
+
+impl<T: Float> MatrixInverse for Vec<Vec<T>> {
+	type Output = T;
+	type MatrixOutput = Vec<Vec<T>>;
+
+	fn try_matrix_inverse(&self, tolerance: T) -> Result<Vec<Vec<T>>, MatrixError> {
+		let size = square_size(self)?;
+		let identity = (0..size)
+			.map(|row| {
+				(0..size)
+					.map(|column| if row == column { T::one() } else { T::zero() })
+					.collect()
+			})
+			.collect();
+		gauss_jordan_inverse(self.clone(), identity, size, tolerance)
+	}
+}
+
+#[cfg(test)]
+mod test_vec_matrix_inverse {
+	use crate::{Fsize, MatrixError, MatrixInverse, MatrixOperations};
+
+	#[test]
+	fn test_vec_matrix_inverse() {
+		let matrix_a: Vec<Vec<Fsize>> = vec![vec![4.0, 7.0], vec![2.0, 6.0]];
+		let matrix_b: Vec<Vec<Fsize>> = vec![
+			vec![0.0, 2.0, 1.0, 3.0],
+			vec![1.0, 0.0, 4.0, 1.0],
+			vec![2.0, 1.0, 0.0, 5.0],
+			vec![3.0, 1.0, 1.0, 0.0],
+		];
+
+		let inverse_a = matrix_a.matrix_inverse(1e-12);
+		let expected_a: Vec<Vec<Fsize>> = vec![vec![0.6, -0.7], vec![-0.2, 0.4]];
+		for row in 0..2 {
+			for column in 0..2 {
+				assert!((inverse_a[row][column] - expected_a[row][column]).abs() < 1e-9);
+			}
+		}
+		// the zero in the top left corner needs a row swap
+		let product = matrix_b.matrix_mult_matrix(&matrix_b.matrix_inverse(1e-12));
+		for (row, vec_row) in product.iter().enumerate() {
+			for (column, value) in vec_row.iter().enumerate() {
+				let expected = if row == column { 1.0 } else { 0.0 };
+				assert!((value - expected).abs() < 1e-9);
+			}
+		}
+	}
+	#[test]
+	fn test_try_vec_matrix_inverse() {
+		let singular: Vec<Vec<Fsize>> = vec![vec![1.0, 2.0], vec![2.0, 4.0]];
+		let nearly_singular: Vec<Vec<Fsize>> = vec![vec![1.0, 2.0], vec![1.0, 2.0 + 1e-9]];
+		let not_square: Vec<Vec<Fsize>> = vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]];
+
+		assert_eq!(
+			Err(MatrixError::Singular),
+			singular.try_matrix_inverse(1e-12)
+		);
+		assert_eq!(
+			Err(MatrixError::Singular),
+			nearly_singular.try_matrix_inverse(1e-6)
+		);
+		assert!(nearly_singular.try_matrix_inverse(1e-12).is_ok());
+		assert_eq!(
+			Err(MatrixError::NotSquare {
+				rows: 2,
+				columns: 3
+			}),
+			not_square.try_matrix_inverse(1e-12)
+		);
+	}
+}
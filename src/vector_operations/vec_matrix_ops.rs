@@ -1,77 +1,183 @@
-use crate::{double_for_loop_operation, single_for_loop_operation, MatrixOperations};
+use crate::decompositions::solve_system;
+use crate::{
+	double_for_loop_operation, matrix_shape, single_for_loop_operation, square_determinant,
+	square_size, MatrixError, MatrixOperations,
+};
+use num::Float;
 use std::ops::{Add, AddAssign, Mul, Sub};
 
-impl<T> MatrixOperations<Vec<Vec<T>>, Vec<T>, T> for Vec<Vec<T>>
+impl<T> MatrixOperations<[Vec<T>], [T], T> for [Vec<T>]
 where
 	T: Add<T, Output = T> + Sub<T, Output = T> + Mul<T, Output = T> + AddAssign + Copy,
 {
 	type Output = T;
-	fn matrix_add_matrix(&self, rhs: &Vec<Vec<T>>) -> Vec<Vec<T>> {
-		if self.len() != rhs.len() {
-			panic!("The rows dimensions do not match !");
-		} else if self[0].len() != rhs[0].len() {
-			panic!("The column dimension do not match")
+	type MatrixOutput = Vec<Vec<T>>;
+	type VectorOutput = Vec<T>;
+
+	fn try_matrix_add_matrix(&self, rhs: &[Vec<T>]) -> Result<Vec<Vec<T>>, MatrixError> {
+		let self_shape = matrix_shape(self)?;
+		let rhs_shape = matrix_shape(rhs)?;
+		if self_shape != rhs_shape {
+			return Err(MatrixError::DimensionMismatch {
+				lhs: self_shape,
+				rhs: rhs_shape,
+			});
 		}
-		let row_length: usize = rhs.len();
-		let column_length: usize = rhs[0].len();
+		let (row_length, column_length) = rhs_shape;
 
 		let matrix_add_matrix_op = |row_vec: &mut Vec<T>, row: usize, column: usize| {
 			row_vec.push(self[row][column] + rhs[row][column])
 		};
-		double_for_loop_operation(row_length, column_length, matrix_add_matrix_op)
+		Ok(double_for_loop_operation(
+			row_length,
+			column_length,
+			matrix_add_matrix_op,
+		))
 	}
 
 	#[allow(clippy::eq_op)]
 	#[allow(clippy::needless_range_loop)]
-	fn matrix_mult_matrix(&self, rhs: &Vec<Vec<T>>) -> Vec<Vec<T>> {
-		if self[0].len() != rhs.len() {
-			panic!("Self matrix columns length does not match rhs row count")
+	fn try_matrix_mult_matrix(&self, rhs: &[Vec<T>]) -> Result<Vec<Vec<T>>, MatrixError> {
+		let self_shape = matrix_shape(self)?;
+		let rhs_shape = matrix_shape(rhs)?;
+		if self_shape.1 != rhs_shape.0 {
+			return Err(MatrixError::DimensionMismatch {
+				lhs: self_shape,
+				rhs: rhs_shape,
+			});
 		}
 
-		let row_length = self.len();
-		let column_length = rhs[0].len();
+		let row_length = self_shape.0;
+		let inner_length = self_shape.1;
+		let column_length = rhs_shape.1;
 
 		let matrix_mult_matrix_op = |row_vec: &mut Vec<T>, row: usize, column: usize| {
 			let mut temp = self[0][0] - self[0][0];
-			for index in 0..column_length {
+			for index in 0..inner_length {
 				temp += self[row][index] * rhs[index][column];
 			}
 			row_vec.push(temp);
 		};
-		double_for_loop_operation(row_length, column_length, matrix_mult_matrix_op)
+		Ok(double_for_loop_operation(
+			row_length,
+			column_length,
+			matrix_mult_matrix_op,
+		))
 	}
 
 	#[allow(clippy::eq_op)]
 	#[allow(clippy::needless_range_loop)]
-	fn matrix_mult_point(&self, rhs: &Vec<T>) -> Vec<T> {
-		if self[0].len() != rhs.len() {
-			panic!("Self matrix columns length does not match rhs row count")
+	fn try_matrix_mult_point(&self, rhs: &[T]) -> Result<Vec<T>, MatrixError> {
+		let self_shape = matrix_shape(self)?;
+		if rhs.is_empty() {
+			return Err(MatrixError::EmptyOperand);
+		} else if self_shape.1 != rhs.len() {
+			return Err(MatrixError::DimensionMismatch {
+				lhs: self_shape,
+				rhs: (rhs.len(), 1),
+			});
 		}
-		let mut point: Vec<T> = Vec::with_capacity(self[0].len());
+		let mut point: Vec<T> = Vec::with_capacity(self_shape.0);
 
 		let matrix_mult_point_op = |row: usize| {
 			let mut temp = self[0][0] - self[0][0];
-			for column in 0..self[0].len() {
+			for column in 0..self_shape.1 {
 				temp += self[row][column] * rhs[column];
 			}
 			point.push(temp);
 		};
-		single_for_loop_operation(self.len(), matrix_mult_point_op);
-		point
+		single_for_loop_operation(self_shape.0, matrix_mult_point_op);
+		Ok(point)
 	}
-	fn scaler_mult_matrix(&self, rhs: T) -> Vec<Vec<T>> {
-		let row_length: usize = self.len();
-		let column_length: usize = self[0].len();
+	fn try_scaler_mult_matrix(&self, rhs: T) -> Result<Vec<Vec<T>>, MatrixError> {
+		let (row_length, column_length) = matrix_shape(self)?;
 
 		let scaler_mult_matrix_op =
 			|row_vec: &mut Vec<T>, row: usize, column: usize| row_vec.push(self[row][column] * rhs);
-		double_for_loop_operation(row_length, column_length, scaler_mult_matrix_op)
+		Ok(double_for_loop_operation(
+			row_length,
+			column_length,
+			scaler_mult_matrix_op,
+		))
+	}
+	fn try_transpose(&self) -> Result<Vec<Vec<T>>, MatrixError> {
+		let (row_length, column_length) = matrix_shape(self)?;
+
+		let transpose_op =
+			|row_vec: &mut Vec<T>, row: usize, column: usize| row_vec.push(self[column][row]);
+		Ok(double_for_loop_operation(
+			column_length,
+			row_length,
+			transpose_op,
+		))
+	}
+
+	#[allow(clippy::eq_op)]
+	fn try_trace(&self) -> Result<T, MatrixError> {
+		let size = square_size(self)?;
+		let mut sum = self[0][0] - self[0][0];
+		let trace_op = |index: usize| sum += self[index][index];
+		single_for_loop_operation(size, trace_op);
+		Ok(sum)
+	}
+	fn try_determinant(&self) -> Result<T, MatrixError>
+	where
+		T: Float,
+	{
+		let size = square_size(self)?;
+		Ok(square_determinant(self.to_vec(), size))
+	}
+	fn try_solve(&self, rhs: &[T]) -> Result<Vec<T>, MatrixError>
+	where
+		T: Float,
+	{
+		solve_system(self, rhs)
+	}
+}
+
+impl<T> MatrixOperations<Vec<Vec<T>>, Vec<T>, T> for Vec<Vec<T>>
+where
+	T: Add<T, Output = T> + Sub<T, Output = T> + Mul<T, Output = T> + AddAssign + Copy,
+{
+	type Output = T;
+	type MatrixOutput = Vec<Vec<T>>;
+	type VectorOutput = Vec<T>;
+
+	fn try_matrix_add_matrix(&self, rhs: &Vec<Vec<T>>) -> Result<Vec<Vec<T>>, MatrixError> {
+		self.as_slice().try_matrix_add_matrix(rhs.as_slice())
+	}
+	fn try_matrix_mult_matrix(&self, rhs: &Vec<Vec<T>>) -> Result<Vec<Vec<T>>, MatrixError> {
+		self.as_slice().try_matrix_mult_matrix(rhs.as_slice())
+	}
+	fn try_matrix_mult_point(&self, rhs: &Vec<T>) -> Result<Vec<T>, MatrixError> {
+		self.as_slice().try_matrix_mult_point(rhs.as_slice())
+	}
+	fn try_scaler_mult_matrix(&self, rhs: T) -> Result<Vec<Vec<T>>, MatrixError> {
+		self.as_slice().try_scaler_mult_matrix(rhs)
+	}
+	fn try_transpose(&self) -> Result<Vec<Vec<T>>, MatrixError> {
+		self.as_slice().try_transpose()
+	}
+	fn try_trace(&self) -> Result<T, MatrixError> {
+		self.as_slice().try_trace()
+	}
+	fn try_determinant(&self) -> Result<T, MatrixError>
+	where
+		T: Float,
+	{
+		self.as_slice().try_determinant()
+	}
+	fn try_solve(&self, rhs: &Vec<T>) -> Result<Vec<T>, MatrixError>
+	where
+		T: Float,
+	{
+		self.as_slice().try_solve(rhs.as_slice())
 	}
 }
 
 #[cfg(test)]
 mod test_overload {
-	use crate::MatrixOperations;
+	use crate::{Fsize, MatrixError, MatrixInverse, MatrixOperations};
 
 	#[test]
 	fn test_vec_matrix_add_matrix() {
@@ -324,4 +430,211 @@ mod test_overload {
 			matrix_c.matrix_mult_point(&point_c)
 		)
 	}
+	#[test]
+	fn test_try_matrix_operations() {
+		let matrix_a: Vec<Vec<Fsize>> = vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]];
+		let matrix_b: Vec<Vec<Fsize>> = vec![vec![1.0, 0.0], vec![0.0, 1.0], vec![1.0, 1.0]];
+		let ragged: Vec<Vec<Fsize>> = vec![vec![1.0, 2.0], vec![3.0]];
+		let empty: Vec<Vec<Fsize>> = Vec::new();
+
+		assert_eq!(
+			Ok(vec![vec![4.0, 5.0], vec![10.0, 11.0]]),
+			matrix_a.try_matrix_mult_matrix(&matrix_b)
+		);
+		assert_eq!(
+			Err(MatrixError::DimensionMismatch {
+				lhs: (2, 3),
+				rhs: (3, 2)
+			}),
+			matrix_a.try_matrix_add_matrix(&matrix_b)
+		);
+		assert_eq!(
+			Err(MatrixError::DimensionMismatch {
+				lhs: (2, 3),
+				rhs: (2, 3)
+			}),
+			matrix_a.try_matrix_mult_matrix(&matrix_a)
+		);
+		assert_eq!(
+			Err(MatrixError::DimensionMismatch {
+				lhs: (2, 3),
+				rhs: (2, 1)
+			}),
+			matrix_a.try_matrix_mult_point(&vec![1.0, 1.0])
+		);
+		assert_eq!(
+			Err(MatrixError::RaggedRows {
+				row: 1,
+				expected: 2,
+				found: 1
+			}),
+			ragged.try_scaler_mult_matrix(2.0)
+		);
+		assert_eq!(
+			Err(MatrixError::EmptyOperand),
+			empty.try_scaler_mult_matrix(2.0)
+		);
+		assert!(std::panic::catch_unwind(|| empty.scaler_mult_matrix(2.0)).is_err());
+	}
+	#[test]
+	fn test_vec_transpose_trace() {
+		let matrix_a: Vec<Vec<Fsize>> = vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]];
+		let matrix_b: Vec<Vec<Fsize>> = vec![
+			vec![1.0, 2.0, 3.0],
+			vec![4.0, 5.0, 6.0],
+			vec![7.0, 8.0, 9.5],
+		];
+
+		assert_eq!(
+			vec![vec![1.0, 4.0], vec![2.0, 5.0], vec![3.0, 6.0]],
+			matrix_a.transpose()
+		);
+		assert_eq!(15.5, matrix_b.trace());
+		assert_eq!(
+			Err(MatrixError::NotSquare {
+				rows: 2,
+				columns: 3
+			}),
+			matrix_a.try_trace()
+		);
+		assert_eq!(
+			Err(MatrixError::EmptyOperand),
+			Vec::<Vec<Fsize>>::new().try_transpose()
+		);
+	}
+	#[test]
+	fn test_vec_determinant() {
+		let matrix_a: Vec<Vec<Fsize>> = vec![vec![3.0, 8.0], vec![4.0, 6.0]];
+		let matrix_b: Vec<Vec<Fsize>> = vec![
+			vec![6.0, 1.0, 1.0],
+			vec![4.0, -2.0, 5.0],
+			vec![2.0, 8.0, 7.0],
+		];
+		let matrix_c: Vec<Vec<Fsize>> = vec![
+			vec![1.0, 0.0, 2.0, -1.0],
+			vec![3.0, 0.0, 0.0, 5.0],
+			vec![2.0, 1.0, 4.0, -3.0],
+			vec![1.0, 0.0, 5.0, 0.0],
+		];
+		let matrix_d: Vec<Vec<Fsize>> = vec![
+			vec![0.0, 2.0, 1.0, 3.0, 1.0],
+			vec![1.0, 0.0, 4.0, 1.0, 2.0],
+			vec![2.0, 1.0, 0.0, 5.0, 3.0],
+			vec![3.0, 1.0, 1.0, 0.0, 4.0],
+			vec![1.0, 1.0, 1.0, 1.0, 1.0],
+		];
+		let singular: Vec<Vec<Fsize>> = vec![
+			vec![1.0, 2.0, 3.0, 4.0, 5.0],
+			vec![2.0, 4.0, 6.0, 8.0, 10.0],
+			vec![1.0, 0.0, 1.0, 0.0, 1.0],
+			vec![0.0, 1.0, 0.0, 1.0, 0.0],
+			vec![3.0, 1.0, 4.0, 1.0, 5.0],
+		];
+
+		assert_eq!(-14.0, matrix_a.determinant());
+		assert_eq!(-306.0, matrix_b.determinant());
+		assert_eq!(30.0, matrix_c.determinant());
+		// the determinant of the inverse is the reciprocal
+		let det_d = matrix_d.determinant();
+		let det_d_inverse = matrix_d.matrix_inverse(1e-12).determinant();
+		assert!((det_d * det_d_inverse - 1.0).abs() < 1e-9);
+		assert_eq!(0.0, singular.determinant());
+		assert_eq!(
+			Err(MatrixError::NotSquare {
+				rows: 1,
+				columns: 2
+			}),
+			vec![vec![1.0, 2.0]].try_determinant()
+		);
+	}
+	#[test]
+	fn test_vec_solve() {
+		let upper: Vec<Vec<Fsize>> = vec![
+			vec![2.0, 1.0, -1.0],
+			vec![0.0, -1.0, 3.0],
+			vec![0.0, 0.0, 4.0],
+		];
+		let spd: Vec<Vec<Fsize>> = vec![
+			vec![4.0, 12.0, -16.0],
+			vec![12.0, 37.0, -43.0],
+			vec![-16.0, -43.0, 98.0],
+		];
+		let general: Vec<Vec<Fsize>> = vec![
+			vec![2.0, 1.0, -1.0],
+			vec![-3.0, -1.0, 2.0],
+			vec![-2.0, 1.0, 2.0],
+		];
+		let expected: Vec<Fsize> = vec![1.0, 2.0, 3.0];
+		for matrix in [upper.clone(), upper.transpose(), spd, general].iter() {
+			let x = matrix.solve(&matrix.matrix_mult_point(&expected));
+			for (expected, result) in expected.iter().zip(x.iter()) {
+				assert!((expected - result).abs() < 1e-9);
+			}
+		}
+
+		// tall systems are solved in the least squares sense
+		let tall: Vec<Vec<Fsize>> = vec![vec![1.0, 0.0], vec![1.0, 1.0], vec![1.0, 2.0]];
+		let fit = tall.solve(&vec![0.0, 1.0, 1.0]);
+		assert!((fit[0] - 1.0 / 6.0).abs() < 1e-9);
+		assert!((fit[1] - 0.5).abs() < 1e-9);
+
+		let singular: Vec<Vec<Fsize>> = vec![
+			vec![1.0, 2.0, 3.0],
+			vec![4.0, 5.0, 6.0],
+			vec![7.0, 8.0, 9.0],
+		];
+		assert_eq!(Err(MatrixError::Singular), singular.try_solve(&expected));
+		// positive definite up to rounding, so Cholesky succeeds with a tiny pivot
+		let singular_spd: Vec<Vec<Fsize>> = vec![vec![0.1, 0.3], vec![0.3, 0.9]];
+		assert_eq!(
+			Err(MatrixError::Singular),
+			singular_spd.try_solve(&vec![1.0, 2.0])
+		);
+		assert_eq!(
+			Err(MatrixError::Underdetermined {
+				rows: 2,
+				columns: 3
+			}),
+			tall.transpose().try_solve(&vec![1.0, 2.0])
+		);
+		assert_eq!(
+			Err(MatrixError::DimensionMismatch {
+				lhs: (3, 3),
+				rhs: (2, 1)
+			}),
+			singular.try_solve(&vec![1.0, 2.0])
+		);
+	}
+	#[test]
+	fn test_slice_matrix_operations() {
+		let matrix: Vec<Vec<Fsize>> = vec![
+			vec![1.0, 2.0, 3.0],
+			vec![4.0, 5.0, 6.0],
+			vec![7.0, 8.0, 10.0],
+		];
+		let point: Vec<Fsize> = vec![1.0, 1.0, 2.0];
+		let rows = &matrix[..2];
+
+		assert_eq!(vec![9.0, 21.0], rows.matrix_mult_point(&point));
+		assert_eq!(
+			vec![vec![2.0, 4.0, 6.0], vec![8.0, 10.0, 12.0]],
+			rows.matrix_add_matrix(rows)
+		);
+		assert_eq!(matrix.transpose()[..2], matrix[..].transpose()[..2]);
+		assert_eq!(
+			Err(MatrixError::NotSquare {
+				rows: 2,
+				columns: 3
+			}),
+			rows.try_trace()
+		);
+		assert_eq!(matrix.determinant(), matrix[..].determinant());
+		assert_eq!(
+			Err(MatrixError::DimensionMismatch {
+				lhs: (2, 3),
+				rhs: (2, 1)
+			}),
+			rows.try_matrix_mult_point(&point[..2])
+		);
+	}
 }
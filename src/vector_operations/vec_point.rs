@@ -1,45 +1,59 @@
-use crate::{single_for_loop_operation, PointOperations};
+use crate::{check_same_length, single_for_loop_operation, MatrixError, PointOperations};
 use std::ops::{Add, Mul, Sub};
 
-impl<T> PointOperations<Vec<T>, T> for Vec<T>
+impl<T> PointOperations<[T], T> for [T]
 where
 	T: Mul<T, Output = T> + Sub<T, Output = T> + Add<T, Output = T> + Copy,
 {
 	type Output = T;
-	fn point_mult_scaler(&self, rhs: T) -> Vec<T> {
+	type VectorOutput = Vec<T>;
+
+	fn try_point_mult_scaler(&self, rhs: T) -> Result<Vec<T>, MatrixError> {
 		if self.is_empty() {
-			panic!("Cannot multiply a point of no dimensions");
+			return Err(MatrixError::EmptyOperand);
 		}
 		let mut point: Vec<T> = Vec::with_capacity(self.len());
 		let scal_mult_point_op = |index: usize| point.push(self[index] * rhs);
 		single_for_loop_operation(self.len(), scal_mult_point_op);
-		point
+		Ok(point)
 	}
-	fn point_minus_point(&self, rhs: &Vec<T>) -> Vec<T> {
-		if self.len() != rhs.len() {
-			panic!("Dimensions of points do not match");
-		} else if self.is_empty() || rhs.is_empty() {
-			panic!("Point of zero dimensinality")
-		}
+	fn try_point_minus_point(&self, rhs: &[T]) -> Result<Vec<T>, MatrixError> {
+		check_same_length(self, rhs)?;
 		let mut point: Vec<T> = Vec::with_capacity(self.len());
 		let point_minus_point_op = |index: usize| point.push(self[index] - rhs[index]);
 		single_for_loop_operation(self.len(), point_minus_point_op);
-		point
+		Ok(point)
 	}
-	fn point_plus_vector(&self, rhs: &Vec<T>) -> Vec<T> {
-		if self.len() != rhs.len() {
-			panic!("The dimensions of the point and vector do not match")
-		}
+	fn try_point_plus_vector(&self, rhs: &[T]) -> Result<Vec<T>, MatrixError> {
+		check_same_length(self, rhs)?;
 		let mut point: Vec<T> = Vec::with_capacity(self.len());
 		let point_plut_vector_op = |index: usize| point.push(self[index] + rhs[index]);
 		single_for_loop_operation(self.len(), point_plut_vector_op);
-		point
+		Ok(point)
+	}
+}
+
+impl<T> PointOperations<Vec<T>, T> for Vec<T>
+where
+	T: Mul<T, Output = T> + Sub<T, Output = T> + Add<T, Output = T> + Copy,
+{
+	type Output = T;
+	type VectorOutput = Vec<T>;
+
+	fn try_point_mult_scaler(&self, rhs: T) -> Result<Vec<T>, MatrixError> {
+		self.as_slice().try_point_mult_scaler(rhs)
+	}
+	fn try_point_minus_point(&self, rhs: &Vec<T>) -> Result<Vec<T>, MatrixError> {
+		self.as_slice().try_point_minus_point(rhs.as_slice())
+	}
+	fn try_point_plus_vector(&self, rhs: &Vec<T>) -> Result<Vec<T>, MatrixError> {
+		self.as_slice().try_point_plus_vector(rhs.as_slice())
 	}
 }
 
 #[cfg(test)]
 mod test_vec_point_rs {
-	use crate::{Fsize, PointOperations};
+	use crate::{Fsize, MatrixError, PointOperations};
 
 	#[test]
 	fn test_scaler_mult_point() {
@@ -113,4 +127,30 @@ mod test_vec_point_rs {
 		assert!(result.is_err());
 		assert!(result_2.is_err());
 	}
+	#[test]
+	fn test_try_point_operations() {
+		let point_a: Vec<Fsize> = vec![1.0, 2.0, 3.0];
+		let point_b: Vec<Fsize> = vec![1.0, 2.0];
+		let point_c: Vec<Fsize> = vec![];
+
+		assert_eq!(
+			Ok(vec![0.0, 0.0, 0.0]),
+			point_a.try_point_minus_point(&point_a)
+		);
+		assert_eq!(
+			Err(MatrixError::DimensionMismatch {
+				lhs: (3, 1),
+				rhs: (2, 1)
+			}),
+			point_a.try_point_plus_vector(&point_b)
+		);
+		assert_eq!(
+			Err(MatrixError::EmptyOperand),
+			point_c.try_point_mult_scaler(2.0)
+		);
+		assert_eq!(
+			Err(MatrixError::EmptyOperand),
+			point_c.try_point_plus_vector(&point_c)
+		);
+	}
 }
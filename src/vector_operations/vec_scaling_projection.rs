@@ -1,36 +1,102 @@
-use crate::{single_for_loop_operation, VecScalingProjection, VectorOps};
+use crate::{
+	check_same_length, single_for_loop_operation, MatrixError, VecScalingProjection, VectorOps,
+};
 use num::Float;
 use std::ops::{AddAssign, Mul, SubAssign};
 
-impl<T> VecScalingProjection<Vec<T>> for Vec<T>
+impl<T> VecScalingProjection<[T]> for [T]
 where
-	T: AddAssign + SubAssign + Mul<T, Output = T> + Float + std::fmt::Debug,
+	T: AddAssign + SubAssign + Mul<T, Output = T> + Float,
 {
 	type Output = T;
+	type VectorOutput = Vec<T>;
 
 	#[allow(clippy::eq_op)]
-	fn magnitude(&self) -> T {
+	fn try_magnitude(&self) -> Result<T, MatrixError> {
 		if self.is_empty() {
-			panic!("Cannot get length of a length zero vector")
+			return Err(MatrixError::EmptyOperand);
 		}
 		let mut result: T = self[0] - self[0];
 		let magnitude_vec_op = |index: usize| result += self[index] * self[index];
 		single_for_loop_operation(self.len(), magnitude_vec_op);
-		result.sqrt()
+		Ok(result.sqrt())
+	}
+	fn try_vec_scalar_components(&self, rhs: &[T]) -> Result<T, MatrixError> {
+		let dot = self.try_dot(rhs)?;
+		let rhs_mag: T = rhs.try_magnitude()?;
+		if rhs_mag == T::zero() {
+			return Err(MatrixError::ZeroMagnitude);
+		}
+		Ok(dot / rhs_mag)
+	}
+	fn try_vec_projection(&self, rhs: &[T]) -> Result<Vec<T>, MatrixError> {
+		let dot = self.try_dot(rhs)?;
+		let rhs_mag: T = rhs.try_magnitude()?;
+		if rhs_mag == T::zero() {
+			return Err(MatrixError::ZeroMagnitude);
+		}
+		let scaler: T = dot / (rhs_mag * rhs_mag);
+		rhs.try_vec_scal(scaler)
+	}
+	fn try_normalize(&self) -> Result<Vec<T>, MatrixError> {
+		let magnitude: T = self.try_magnitude()?;
+		if magnitude == T::zero() {
+			return Err(MatrixError::ZeroMagnitude);
+		}
+		self.try_vec_scal(T::one() / magnitude)
+	}
+	fn try_distance(&self, rhs: &[T]) -> Result<T, MatrixError> {
+		check_same_length(self, rhs)?;
+		let mut result: T = T::zero();
+		let distance_vec_op = |index: usize| {
+			let difference = self[index] - rhs[index];
+			result += difference * difference
+		};
+		single_for_loop_operation(self.len(), distance_vec_op);
+		Ok(result.sqrt())
+	}
+	fn try_angle(&self, rhs: &[T]) -> Result<T, MatrixError> {
+		let dot = self.try_dot(rhs)?;
+		let magnitudes: T = self.try_magnitude()? * rhs.try_magnitude()?;
+		if magnitudes == T::zero() {
+			return Err(MatrixError::ZeroMagnitude);
+		}
+		// rounding can push the cosine of (anti)parallel vectors just past ±1
+		let cosine = (dot / magnitudes).max(-T::one()).min(T::one());
+		Ok(cosine.acos())
+	}
+}
+
+impl<T> VecScalingProjection<Vec<T>> for Vec<T>
+where
+	T: AddAssign + SubAssign + Mul<T, Output = T> + Float,
+{
+	type Output = T;
+	type VectorOutput = Vec<T>;
+
+	fn try_magnitude(&self) -> Result<T, MatrixError> {
+		self.as_slice().try_magnitude()
 	}
-	fn vec_scalar_components(&self, rhs: &Vec<T>) -> T {
-		self.dot(rhs) / rhs.magnitude()
+	fn try_vec_scalar_components(&self, rhs: &Vec<T>) -> Result<T, MatrixError> {
+		self.as_slice().try_vec_scalar_components(rhs.as_slice())
 	}
-	fn vec_projection(&self, rhs: &Vec<T>) -> Vec<T> {
-		let rhs_mag: T = rhs.magnitude();
-		let scaler: T = self.dot(rhs) / (rhs_mag * rhs_mag);
-		rhs.vec_scal(scaler)
+	fn try_vec_projection(&self, rhs: &Vec<T>) -> Result<Vec<T>, MatrixError> {
+		self.as_slice().try_vec_projection(rhs.as_slice())
+	}
+	fn try_normalize(&self) -> Result<Vec<T>, MatrixError> {
+		self.as_slice().try_normalize()
+	}
+	fn try_distance(&self, rhs: &Vec<T>) -> Result<T, MatrixError> {
+		self.as_slice().try_distance(rhs.as_slice())
+	}
+	fn try_angle(&self, rhs: &Vec<T>) -> Result<T, MatrixError> {
+		self.as_slice().try_angle(rhs.as_slice())
 	}
 }
 
 #[cfg(test)]
 mod test_vec_ops {
-	use crate::{Fsize, VecScalingProjection};
+	use crate::{Fsize, MatrixError, VecScalingProjection, VectorOps};
 	#[test]
 	fn test_magnitude() {
 		let vec_1 = vec![1.0, 2.0, 3.0, 4.0];
@@ -93,4 +159,53 @@ mod test_vec_ops {
 			vec_5.vec_projection(&vec_6)
 		);
 	}
+	#[test]
+	fn test_try_scaling_projection() {
+		let vec_1: Vec<Fsize> = vec![3.0, 4.0];
+		let vec_2: Vec<Fsize> = vec![1.0, 0.0, 0.0];
+		let zero: Vec<Fsize> = vec![0.0, 0.0];
+		let empty: Vec<Fsize> = Vec::new();
+
+		assert_eq!(Ok(5.0), vec_1.try_magnitude());
+		assert_eq!(Err(MatrixError::EmptyOperand), empty.try_magnitude());
+		assert_eq!(
+			Err(MatrixError::ZeroMagnitude),
+			vec_1.try_vec_scalar_components(&zero)
+		);
+		assert_eq!(
+			Err(MatrixError::ZeroMagnitude),
+			vec_1.try_vec_projection(&zero)
+		);
+		assert_eq!(
+			Err(MatrixError::DimensionMismatch {
+				lhs: (2, 1),
+				rhs: (3, 1)
+			}),
+			vec_1.try_vec_projection(&vec_2)
+		);
+	}
+	#[test]
+	fn test_normalize_distance_angle() {
+		let vec_1: Vec<Fsize> = vec![1.0, 2.0, 2.0];
+		let vec_2: Vec<Fsize> = vec![4.0, 6.0, 2.0];
+		let vec_3: Vec<Fsize> = vec![0.0, 3.0];
+		let zero: Vec<Fsize> = vec![0.0, 0.0, 0.0];
+		let half_pi = std::f64::consts::FRAC_PI_2 as Fsize;
+
+		assert_eq!(vec![1.0 / 3.0, 2.0 / 3.0, 2.0 / 3.0], vec_1.normalize());
+		assert_eq!(Err(MatrixError::ZeroMagnitude), zero.try_normalize());
+		assert_eq!(5.0, vec_1.distance(&vec_2));
+		assert_eq!(0.0, vec_1.distance(&vec_1));
+		assert!((vec![1.0, 0.0].angle(&vec_3) - half_pi).abs() < 1e-6);
+		assert!(vec_2.angle(&vec_2.vec_scal(3.0)).abs() < 1e-6);
+		assert!((vec_2.angle(&vec_2.vec_scal(-1.0)) - 2.0 * half_pi).abs() < 1e-6);
+		assert_eq!(Err(MatrixError::ZeroMagnitude), vec_1.try_angle(&zero));
+		assert_eq!(
+			Err(MatrixError::DimensionMismatch {
+				lhs: (3, 1),
+				rhs: (2, 1)
+			}),
+			vec_1.try_distance(&vec_3)
+		);
+	}
 }
@@ -0,0 +1,93 @@
+use crate::{Axis, MatrixError, ThreeDimMatrixOps};
+use num::Float;
+use std::ops::{Add, Mul};
+
+impl<T> ThreeDimMatrixOps<T> for Vec<T>
+where
+	T: Mul<T, Output = T> + Add<T, Output = T> + Float + Copy + From<u8>,
+{
+	type Output = T;
+
+	fn try_matrix_three_dim_rotate(
+		&self,
+		axis: Axis,
+		theta_rad: T,
+		homogeneous_flag: bool,
+	) -> Result<Vec<T>, MatrixError> {
+		if homogeneous_flag && self.len() != 4 {
+			return Err(MatrixError::NonHomogeneous);
+		} else if !homogeneous_flag && self.len() != 3 {
+			return Err(MatrixError::DimensionMismatch {
+				lhs: (self.len(), 1),
+				rhs: (3, 1),
+			});
+		}
+
+		let (cos, sin) = (theta_rad.cos(), theta_rad.sin());
+		let mut point = match axis {
+			Axis::X => vec![
+				self[0],
+				self[1] * cos - self[2] * sin,
+				self[1] * sin + self[2] * cos,
+			],
+			Axis::Y => vec![
+				self[0] * cos + self[2] * sin,
+				self[1],
+				self[2] * cos - self[0] * sin,
+			],
+			Axis::Z => vec![
+				self[0] * cos - self[1] * sin,
+				self[0] * sin + self[1] * cos,
+				self[2],
+			],
+		};
+		if homogeneous_flag {
+			point.push(self[3]);
+		}
+		Ok(point)
+	}
+}
+
+#[cfg(test)]
+mod test_three_dim_matrix_ops {
+	use crate::{Axis, Fsize, MatrixError, ThreeDimMatrixOps};
+
+	#[test]
+	fn test_matrix_three_dim_rotate() {
+		let half_pi = std::f64::consts::FRAC_PI_2 as Fsize;
+		let point_a: Vec<Fsize> = vec![1.0, 2.0, 3.0];
+		let point_b: Vec<Fsize> = vec![1.0, 2.0, 3.0, 1.0];
+
+		let rotated_x = point_a.matrix_three_dim_rotate(Axis::X, half_pi, false);
+		let rotated_y = point_a.matrix_three_dim_rotate(Axis::Y, half_pi, false);
+		let rotated_z = point_b.matrix_three_dim_rotate(Axis::Z, half_pi, true);
+
+		let expected: [(Vec<Fsize>, Vec<Fsize>); 3] = [
+			(rotated_x, vec![1.0, -3.0, 2.0]),
+			(rotated_y, vec![3.0, 2.0, -1.0]),
+			(rotated_z, vec![-2.0, 1.0, 3.0, 1.0]),
+		];
+		for (result, expected) in expected.iter() {
+			assert_eq!(expected.len(), result.len());
+			for index in 0..result.len() {
+				assert!((result[index] - expected[index]).abs() < 1e-6);
+			}
+		}
+	}
+	#[test]
+	fn test_try_matrix_three_dim_rotate() {
+		let point_a: Vec<Fsize> = vec![1.0, 2.0, 3.0];
+
+		assert_eq!(
+			Err(MatrixError::NonHomogeneous),
+			point_a.try_matrix_three_dim_rotate(Axis::X, 1.0, true)
+		);
+		assert_eq!(
+			Err(MatrixError::DimensionMismatch {
+				lhs: (2, 1),
+				rhs: (3, 1)
+			}),
+			vec![1.0, 2.0].try_matrix_three_dim_rotate(Axis::Z, 1.0, false)
+		);
+	}
+}
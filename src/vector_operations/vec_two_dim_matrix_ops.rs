@@ -1,4 +1,4 @@
-use crate::TwoDimMatrixOps;
+use crate::{MatrixError, TwoDimMatrixOps};
 use num::Float;
 use std::ops::{Add, Mul};
 
@@ -8,14 +8,21 @@ where
 {
 	type Output = T;
 
-	fn matrix_two_dim_rotate(&self, theta_rad: T, homogeneous_flag: bool) -> Vec<T> {
+	fn try_matrix_two_dim_rotate(
+		&self,
+		theta_rad: T,
+		homogeneous_flag: bool,
+	) -> Result<Vec<T>, MatrixError> {
 		if homogeneous_flag && self.len() != 3 {
-			panic!("Homogeneous 2 dim vector is of size 3");
+			return Err(MatrixError::NonHomogeneous);
 		} else if !homogeneous_flag && self.len() != 2 {
-			panic!("Non homogeneous 2 dim vector is of size 2");
+			return Err(MatrixError::DimensionMismatch {
+				lhs: (self.len(), 1),
+				rhs: (2, 1),
+			});
 		}
 
-		if homogeneous_flag {
+		Ok(if homogeneous_flag {
 			vec![
 				self[0] * theta_rad.cos() - self[1] * theta_rad.sin(),
 				self[0] * theta_rad.sin() + self[1] * theta_rad.cos(),
@@ -26,15 +33,16 @@ where
 				self[0] * theta_rad.cos() - self[1] * theta_rad.sin(),
 				self[0] * theta_rad.sin() + self[1] * theta_rad.cos(),
 			]
-		}
+		})
 	}
 }
 
 #[cfg(test)]
 mod test_two_dim_matrix_ops {
-	use crate::TwoDimMatrixOps;
+	use crate::{Fsize, MatrixError, TwoDimMatrixOps};
 
 	#[test]
+	#[allow(clippy::approx_constant)]
 	fn test_matrix_two_dim_rotate() {
 		let matrix_a = vec![1.0, 0.0];
 		assert_eq!(
@@ -57,4 +65,21 @@ mod test_two_dim_matrix_ops {
 			matrix_d.matrix_two_dim_rotate(3.14159, true)
 		);
 	}
+	#[test]
+	fn test_try_matrix_two_dim_rotate() {
+		let point_a: Vec<Fsize> = vec![1.0, 0.0, 1.0];
+		let point_b: Vec<Fsize> = vec![1.0, 0.0];
+
+		assert_eq!(
+			Err(MatrixError::NonHomogeneous),
+			point_b.try_matrix_two_dim_rotate(1.0, true)
+		);
+		assert_eq!(
+			Err(MatrixError::DimensionMismatch {
+				lhs: (3, 1),
+				rhs: (2, 1)
+			}),
+			point_a.try_matrix_two_dim_rotate(1.0, false)
+		);
+	}
 }
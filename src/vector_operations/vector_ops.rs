@@ -1,47 +1,80 @@
-use crate::{single_for_loop_operation, VectorOps};
+use crate::{check_same_length, single_for_loop_operation, MatrixError, VectorOps};
+use num::Zero;
 use std::ops::{Add, AddAssign, Mul, Sub};
 
-impl<T> VectorOps<Vec<T>, T> for Vec<T>
+impl<T> VectorOps<[T], T> for [T]
 where
-	T: Add<T, Output = T> + Mul<T, Output = T> + Sub<T, Output = T> + AddAssign + Copy,
+	T: Add<T, Output = T> + Mul<T, Output = T> + Sub<T, Output = T> + AddAssign + Zero + Copy,
 {
 	type Output = T;
+	type VectorOutput = Vec<T>;
 
-	fn vec_scal(&self, scal: T) -> Vec<T> {
+	fn try_vec_scal(&self, scal: T) -> Result<Vec<T>, MatrixError> {
 		let mut result: Vec<T> = Vec::new();
 		let scal_vec_op = |index: usize| result.push(self[index] * scal);
 		single_for_loop_operation(self.len(), scal_vec_op);
-		result
+		Ok(result)
 	}
-	fn vec_add(&self, rhs: &Vec<T>) -> Vec<T> {
-		if self.is_empty() || rhs.is_empty() {
-			panic!("Cannot add a vector with zero elements");
-		} else if self.len() != rhs.len() {
-			panic!("Cant add two vectors with different length");
-		}
+	fn try_vec_add(&self, rhs: &[T]) -> Result<Vec<T>, MatrixError> {
+		check_same_length(self, rhs)?;
 		let mut result: Vec<T> = Vec::new();
 		let add_vec_op = |index: usize| result.push(self[index] + rhs[index]);
 		single_for_loop_operation(self.len(), add_vec_op);
-		result
+		Ok(result)
 	}
 
 	#[allow(clippy::eq_op)]
-	fn dot(&self, rhs: &Vec<T>) -> T {
-		if self.is_empty() || rhs.is_empty() {
-			panic!("Trying to dot product with a zeroth scaler");
-		} else if self.len() != rhs.len() {
-			panic!("Cant dot product two vectors with different length");
-		}
+	fn try_dot(&self, rhs: &[T]) -> Result<T, MatrixError> {
+		check_same_length(self, rhs)?;
 		let mut sum: Self::Output = rhs[0] - rhs[0];
 		let dot_vec_op = |index: usize| sum += self[index] * rhs[index];
 		single_for_loop_operation(self.len(), dot_vec_op);
-		sum
+		Ok(sum)
+	}
+	fn try_cross(&self, rhs: &[T]) -> Result<Vec<T>, MatrixError> {
+		check_same_length(self, rhs)?;
+		if self.len() != 3 && self.len() != 4 {
+			return Err(MatrixError::DimensionMismatch {
+				lhs: (self.len(), 1),
+				rhs: (3, 1),
+			});
+		}
+		let mut result = vec![
+			self[1] * rhs[2] - self[2] * rhs[1],
+			self[2] * rhs[0] - self[0] * rhs[2],
+			self[0] * rhs[1] - self[1] * rhs[0],
+		];
+		if self.len() == 4 {
+			result.push(T::zero());
+		}
+		Ok(result)
+	}
+}
+
+impl<T> VectorOps<Vec<T>, T> for Vec<T>
+where
+	T: Add<T, Output = T> + Mul<T, Output = T> + Sub<T, Output = T> + AddAssign + Zero + Copy,
+{
+	type Output = T;
+	type VectorOutput = Vec<T>;
+
+	fn try_vec_scal(&self, scal: T) -> Result<Vec<T>, MatrixError> {
+		self.as_slice().try_vec_scal(scal)
+	}
+	fn try_vec_add(&self, rhs: &Vec<T>) -> Result<Vec<T>, MatrixError> {
+		self.as_slice().try_vec_add(rhs.as_slice())
+	}
+	fn try_dot(&self, rhs: &Vec<T>) -> Result<T, MatrixError> {
+		self.as_slice().try_dot(rhs.as_slice())
+	}
+	fn try_cross(&self, rhs: &Vec<T>) -> Result<Vec<T>, MatrixError> {
+		self.as_slice().try_cross(rhs.as_slice())
 	}
 }
 
 #[cfg(test)]
 mod test_vec_ops {
-	use crate::{Fsize, VectorOps};
+	use crate::{Fsize, MatrixError, VectorOps};
 
 	#[test]
 	fn test_vec_add() {
@@ -78,6 +111,30 @@ mod test_vec_ops {
 		assert!(result_4.is_err());
 	}
 	#[test]
+	fn test_cross() {
+		let vec_1: Vec<Fsize> = vec![2.0, 3.0, 4.0];
+		let vec_2: Vec<Fsize> = vec![5.0, 6.0, 7.0];
+		let point_1: Vec<Fsize> = vec![2.0, 3.0, 4.0, 1.0];
+		let point_2: Vec<Fsize> = vec![5.0, 6.0, 7.0, 1.0];
+		let plane: Vec<Fsize> = vec![1.0, 2.0];
+
+		let cross = vec_1.cross(&vec_2);
+		assert_eq!(vec![-3.0, 6.0, -3.0], cross);
+		assert_eq!(vec![3.0, -6.0, 3.0], vec_2.cross(&vec_1));
+		assert_eq!(0.0, cross.dot(&vec_1));
+		assert_eq!(vec![-3.0, 6.0, -3.0, 0.0], point_1.cross(&point_2));
+		assert_eq!(
+			Err(MatrixError::DimensionMismatch {
+				lhs: (2, 1),
+				rhs: (3, 1)
+			}),
+			plane.try_cross(&plane)
+		);
+		assert!(vec_1.try_cross(&point_1).is_err());
+		let infinite: Vec<Fsize> = vec![Fsize::INFINITY, 0.0, 0.0, 1.0];
+		assert_eq!(0.0, vec![0.0, 1.0, 0.0, 1.0].cross(&infinite)[3]);
+	}
+	#[test]
 	fn test_scal_mult() {
 		let vec_1 = vec![1.0, 1.0, 4.0];
 		let vec_2 = vec![2.0, 3.0, 4.1];
@@ -135,4 +192,30 @@ mod test_vec_ops {
 		assert!(result_3.is_err());
 		assert!(result_4.is_err());
 	}
+	#[test]
+	fn test_try_vec_ops() {
+		let vec_1: Vec<Fsize> = vec![1.0, 2.0, 3.0];
+		let vec_2: Vec<Fsize> = vec![3.0, 4.0];
+		let empty: Vec<Fsize> = Vec::new();
+
+		assert_eq!(Ok(vec![2.0, 4.0, 6.0]), vec_1.try_vec_add(&vec_1));
+		assert_eq!(Ok(14.0), vec_1.try_dot(&vec_1));
+		assert_eq!(
+			Err(MatrixError::DimensionMismatch {
+				lhs: (3, 1),
+				rhs: (2, 1)
+			}),
+			vec_1.try_vec_add(&vec_2)
+		);
+		assert_eq!(
+			Err(MatrixError::DimensionMismatch {
+				lhs: (2, 1),
+				rhs: (3, 1)
+			}),
+			vec_2.try_dot(&vec_1)
+		);
+		assert_eq!(Err(MatrixError::EmptyOperand), empty.try_dot(&vec_1));
+		assert_eq!(Ok(Vec::new()), empty.try_vec_scal(2.0));
+		assert!(empty.vec_scal(2.0).is_empty());
+	}
 }